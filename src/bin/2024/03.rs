@@ -34,12 +34,10 @@ pub fn part_two(input: &str) -> Option<u64> {
     for cap in re.captures_iter(input) {
         if let Some(m) = cap.get(1) {
             match m.as_str() {
-                s if s.starts_with("mul") => {
-                    if enabled {
-                        let first = cap.get(2).unwrap().as_str().parse::<u64>().unwrap();
-                        let second = cap.get(3).unwrap().as_str().parse::<u64>().unwrap();
-                        sum += first * second;
-                    }
+                s if s.starts_with("mul") && enabled => {
+                    let first = cap.get(2).unwrap().as_str().parse::<u64>().unwrap();
+                    let second = cap.get(3).unwrap().as_str().parse::<u64>().unwrap();
+                    sum += first * second;
                 }
                 "do()" => enabled = true,
                 "don't()" => enabled = false,
@@ -63,7 +61,9 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ));
+        assert_eq!(result, Some(48));
     }
 }
@@ -0,0 +1,7 @@
+//! Runs all scaffolded days from a single binary, without invoking cargo for every day.
+//! Use `cargo all --in-process` to run it, which keeps the registry of days up to date.
+mod registry;
+
+fn main() {
+    advent_of_code::template::runner::run_in_process(registry::DAYS);
+}
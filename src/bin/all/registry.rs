@@ -0,0 +1,107 @@
+// This file is generated by `cargo scaffold` and `cargo all --in-process`.
+use advent_of_code::template::{runner::RunDay, Day};
+
+#[path = "../2024/01.rs"]
+#[allow(dead_code)]
+mod day_2024_01;
+#[path = "../2024/02.rs"]
+#[allow(dead_code)]
+mod day_2024_02;
+#[path = "../2024/03.rs"]
+#[allow(dead_code)]
+mod day_2024_03;
+#[path = "../2024/04.rs"]
+#[allow(dead_code)]
+mod day_2024_04;
+#[path = "../2024/05.rs"]
+#[allow(dead_code)]
+mod day_2024_05;
+#[path = "../2024/06.rs"]
+#[allow(dead_code)]
+mod day_2024_06;
+#[path = "../2024/07.rs"]
+#[allow(dead_code)]
+mod day_2024_07;
+#[path = "../2024/08.rs"]
+#[allow(dead_code)]
+mod day_2024_08;
+#[path = "../2024/09.rs"]
+#[allow(dead_code)]
+mod day_2024_09;
+#[path = "../2024/10.rs"]
+#[allow(dead_code)]
+mod day_2024_10;
+#[path = "../2024/11.rs"]
+#[allow(dead_code)]
+mod day_2024_11;
+#[path = "../2024/12.rs"]
+#[allow(dead_code)]
+mod day_2024_12;
+#[path = "../2024/13.rs"]
+#[allow(dead_code)]
+mod day_2024_13;
+#[path = "../2024/14.rs"]
+#[allow(dead_code)]
+mod day_2024_14;
+#[path = "../2024/15.rs"]
+#[allow(dead_code)]
+mod day_2024_15;
+#[path = "../2024/16.rs"]
+#[allow(dead_code)]
+mod day_2024_16;
+#[path = "../2024/17.rs"]
+#[allow(dead_code)]
+mod day_2024_17;
+#[path = "../2024/18.rs"]
+#[allow(dead_code)]
+mod day_2024_18;
+#[path = "../2024/19.rs"]
+#[allow(dead_code)]
+mod day_2024_19;
+#[path = "../2024/20.rs"]
+#[allow(dead_code)]
+mod day_2024_20;
+#[path = "../2024/21.rs"]
+#[allow(dead_code)]
+mod day_2024_21;
+#[path = "../2024/22.rs"]
+#[allow(dead_code)]
+mod day_2024_22;
+#[path = "../2024/23.rs"]
+#[allow(dead_code)]
+mod day_2024_23;
+#[path = "../2024/24.rs"]
+#[allow(dead_code)]
+mod day_2024_24;
+#[path = "../2024/25.rs"]
+#[allow(dead_code)]
+mod day_2024_25;
+
+/// All scaffolded days and the functions that run them.
+pub static DAYS: &[(Day, RunDay)] = &[
+    (day_2024_01::DAY, day_2024_01::run),
+    (day_2024_02::DAY, day_2024_02::run),
+    (day_2024_03::DAY, day_2024_03::run),
+    (day_2024_04::DAY, day_2024_04::run),
+    (day_2024_05::DAY, day_2024_05::run),
+    (day_2024_06::DAY, day_2024_06::run),
+    (day_2024_07::DAY, day_2024_07::run),
+    (day_2024_08::DAY, day_2024_08::run),
+    (day_2024_09::DAY, day_2024_09::run),
+    (day_2024_10::DAY, day_2024_10::run),
+    (day_2024_11::DAY, day_2024_11::run),
+    (day_2024_12::DAY, day_2024_12::run),
+    (day_2024_13::DAY, day_2024_13::run),
+    (day_2024_14::DAY, day_2024_14::run),
+    (day_2024_15::DAY, day_2024_15::run),
+    (day_2024_16::DAY, day_2024_16::run),
+    (day_2024_17::DAY, day_2024_17::run),
+    (day_2024_18::DAY, day_2024_18::run),
+    (day_2024_19::DAY, day_2024_19::run),
+    (day_2024_20::DAY, day_2024_20::run),
+    (day_2024_21::DAY, day_2024_21::run),
+    (day_2024_22::DAY, day_2024_22::run),
+    (day_2024_23::DAY, day_2024_23::run),
+    (day_2024_24::DAY, day_2024_24::run),
+    (day_2024_25::DAY, day_2024_25::run),
+];
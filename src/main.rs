@@ -1,14 +1,19 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, extract, read, scaffold, solve, stars, status, time, verify, watch,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
+use advent_of_code::template::{commands::Failure, Day};
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::{solve::Input, Failure},
+        params::parse_param_arg,
+        Day, Year,
+    };
+    use std::{env, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -17,6 +22,13 @@ mod args {
         Read {
             day: Day,
         },
+        Extract {
+            day: Day,
+            block: Option<usize>,
+            part: u8,
+            list: bool,
+            overwrite: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
@@ -27,63 +39,151 @@ mod args {
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            input: Option<Input>,
+            part: Option<u8>,
+            params: Vec<String>,
         },
         All {
             release: bool,
+            in_process: bool,
+            jobs: usize,
+            part: Option<u8>,
+            timeout: Option<Duration>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            threshold: f64,
+            baseline: Option<String>,
+            part: Option<u8>,
+            timeout: Option<Duration>,
+        },
+        Verify {
+            release: bool,
+            hash: bool,
+        },
+        Watch {
+            day: Day,
+        },
+        Stars {
+            sync: bool,
+        },
+        Status {
+            tests: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    pub fn parse() -> Result<AppArguments, Failure> {
         let mut args = pico_args::Arguments::from_env();
+        let subcommand = args.subcommand()?;
+
+        // the year applies to every subcommand, so it has to be known before any day is parsed.
+        // child commands inherit it via the environment.
+        if let Some(year) = args.opt_value_from_str::<_, Year>("--year")? {
+            env::set_var("AOC_YEAR", year.to_string());
+        }
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                in_process: args.contains("--in-process"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                part: args.opt_value_from_fn("--part", parse_part)?,
+                timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let baseline = args.opt_value_from_str("--baseline")?;
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+                let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    threshold,
+                    baseline,
+                    part,
+                    timeout,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                hash: args.contains("--hash"),
+            },
+            Some("watch") => AppArguments::Watch {
+                day: args.free_from_str()?,
+            },
+            Some("stars") => AppArguments::Stars {
+                sync: args.contains("--sync"),
+            },
+            Some("status") => AppArguments::Status {
+                tests: args.contains("--tests"),
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            Some("extract") => AppArguments::Extract {
                 day: args.free_from_str()?,
-                download: args.contains("--download"),
+                block: args.opt_value_from_str("--block")?,
+                part: args.opt_value_from_fn("--part", parse_part)?.unwrap_or(1),
+                list: args.contains("--list"),
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
+            Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                download: args.contains("--download"),
+                overwrite: args.contains("--overwrite"),
             },
+            Some("solve") => {
+                let day = args.free_from_str()?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let path = args.opt_value_from_str("--input")?;
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+                let params = args.values_from_fn("--param", parse_param_arg)?;
+
+                // the number of `--example` is optional, so it is read as the last free argument.
+                let example = if args.contains("--example") {
+                    Some(args.opt_free_from_str()?)
+                } else {
+                    None
+                };
+
+                let input = match (path, example) {
+                    (Some(_), Some(_)) => {
+                        return Err(Failure::Usage(
+                            "`--input` and `--example` can't be used together.".into(),
+                        ));
+                    }
+                    (Some(path), None) => Some(Input::Path(path)),
+                    (None, Some(part)) => Some(Input::Example(part)),
+                    (None, None) => None,
+                };
+
+                AppArguments::Solve {
+                    day,
+                    release,
+                    submit,
+                    dhat,
+                    input,
+                    part,
+                    params,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
+            Some(x) => return Err(Failure::Usage(format!("unknown command: {x}"))),
+            None => return Err(Failure::Usage("no command specified.".into())),
         };
 
         let remaining = args.finish();
@@ -93,19 +193,75 @@ mod args {
 
         Ok(app_args)
     }
+
+    fn parse_part(value: &str) -> Result<u8, &'static str> {
+        match value.parse() {
+            Ok(part @ (1 | 2)) => Ok(part),
+            _ => Err("expecting part 1 or 2"),
+        }
+    }
+
+    fn parse_timeout(value: &str) -> Result<Duration, &'static str> {
+        value
+            .parse::<f64>()
+            .ok()
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .filter(|timeout| !timeout.is_zero())
+            .ok_or("expecting a timeout in seconds, e.g. 10 or 0.5")
+    }
 }
 
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
-        }
+    let result = match parse() {
+        Err(failure) => Err(failure),
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                release,
+                in_process,
+                jobs,
+                part,
+                timeout,
+            } => all::handle(release, in_process, jobs, part, timeout),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                threshold,
+                baseline,
+                part,
+                timeout,
+            } => time::handle(day, all, store, threshold, baseline, part, timeout),
+            AppArguments::Verify { release, hash } => verify::handle(release, hash),
+            AppArguments::Watch { day } => {
+                watch::handle(day);
+                Ok(())
+            }
+            AppArguments::Stars { sync } => {
+                stars::handle(sync);
+                Ok(())
+            }
+            AppArguments::Status { tests } => {
+                status::handle(tests);
+                Ok(())
+            }
+            AppArguments::Download { day } => {
+                download::handle(day);
+                Ok(())
+            }
+            AppArguments::Read { day } => {
+                read::handle(day);
+                Ok(())
+            }
+            AppArguments::Extract {
+                day,
+                block,
+                part,
+                list,
+                overwrite,
+            } => {
+                extract::handle(day, block, part, list, overwrite);
+                Ok(())
+            }
             AppArguments::Scaffold {
                 day,
                 download,
@@ -115,30 +271,39 @@ fn main() {
                 if download {
                     download::handle(day);
                 }
+                Ok(())
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+                input,
+                part,
+                params,
+            } => {
+                solve::handle(day, release, dhat, submit, input, part, params);
+                Ok(())
             }
+            #[cfg(feature = "today")]
+            AppArguments::Today => match Day::today() {
+                Some(day) => {
+                    scaffold::handle(day, false);
+                    download::handle(day);
+                    read::handle(day);
+                    Ok(())
+                }
+                None => Err(Failure::Usage(
+                    "`today` command can only be run during advent, i.e. between the 1st and \
+                    the last day of the calendar in december. Please use `scaffold` with a specific day."
+                        .into(),
+                )),
+            },
         },
     };
+
+    if let Err(failure) = result {
+        eprintln!("Error: {failure}");
+        process::exit(failure.exit_code());
+    }
 }
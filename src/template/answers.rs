@@ -0,0 +1,430 @@
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    fs,
+    hash::{BuildHasher, Hasher},
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{get_data_path, Day, Year};
+
+static ANSWERS_FILE_PATH: &str = "answers.json";
+
+/// Represents the answers of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answer {
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            part_1: None,
+            part_2: None,
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of comparing a computed answer with the stored one.
+/// The expected value is not known if the answers are hashed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verification {
+    Pass,
+    Fail {
+        expected: Option<String>,
+        actual: Option<String>,
+    },
+    Missing,
+}
+
+/// Represents the accepted answers for a set of days.
+/// If `salt` is set, the answers are stored as salted hashes. This hides them from a casual look at the file,
+/// but small answers can be recovered by hashing candidates, as the salt is stored alongside.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub salt: Option<String>,
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to the JSON file of `year`.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_data_path(year, ANSWERS_FILE_PATH))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from the JSON file of `year`. If not present, returns empty answers.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_data_path(year, ANSWERS_FILE_PATH))
+            .map_err(|x| x.to_string())
+            .and_then(|json| Answers::from_json(&json, year))
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|a| a.day == day)
+            .and_then(|a| a.get(part))
+    }
+
+    /// Store the accepted answer for one part of a day, overwriting a previous one.
+    pub fn set(&mut self, day: Day, part: u8, answer: &str) {
+        let answer = match &self.salt {
+            Some(salt) => hash_answer(salt, day, part, answer),
+            None => answer.to_string(),
+        };
+
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer::new(day));
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        match part {
+            1 => self.data[index].part_1 = Some(answer),
+            2 => self.data[index].part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    pub fn is_hashed(&self) -> bool {
+        self.salt.is_some()
+    }
+
+    /// Replace all plain text answers with salted hashes, using a fresh salt.
+    /// Answers that are already hashed are returned unchanged.
+    pub fn into_hashed(self) -> Self {
+        if self.is_hashed() {
+            return self;
+        }
+
+        let salt = generate_salt();
+        let hash = |day: Day, part: u8, answer: Option<String>| {
+            answer.map(|answer| hash_answer(&salt, day, part, &answer))
+        };
+
+        let data = self
+            .data
+            .into_iter()
+            .map(|a| Answer {
+                day: a.day,
+                part_1: hash(a.day, 1, a.part_1),
+                part_2: hash(a.day, 2, a.part_2),
+            })
+            .collect();
+
+        Answers {
+            salt: Some(salt),
+            data,
+        }
+    }
+
+    /// Compare a computed answer with the stored one.
+    pub fn verify(&self, day: Day, part: u8, actual: Option<&str>) -> Verification {
+        let Some(expected) = self.get(day, part) else {
+            return Verification::Missing;
+        };
+
+        let is_match = match (&self.salt, actual) {
+            (Some(salt), Some(actual)) => hash_answer(salt, day, part, actual) == expected,
+            (None, Some(actual)) => actual == expected,
+            (_, None) => false,
+        };
+
+        if is_match {
+            Verification::Pass
+        } else {
+            Verification::Fail {
+                expected: (!self.is_hashed()).then(|| expected.to_string()),
+                actual: actual.map(ToString::to_string),
+            }
+        }
+    }
+}
+
+/// Hash an answer together with the salt and its day and part, as hex string.
+fn hash_answer(salt: &str, day: Day, part: u8, answer: &str) -> String {
+    Sha256::digest(format!("{salt}:{day}:{part}:{answer}"))
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn generate_salt() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    format!("{:016x}", hasher.finish())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        if let Some(salt) = value.salt {
+            map.insert("salt".into(), JsonValue::String(salt));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl Answers {
+    /// Parse the answers of `year` from a JSON document. Days are stored without their year.
+    pub fn from_json(value: &str, year: Year) -> Result<Self, String> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let salt = match json.get("salt") {
+            Some(salt) => Some(
+                salt.get::<String>()
+                    .ok_or("expected `json.salt` to be a string.")?
+                    .clone(),
+            ),
+            None => None,
+        };
+
+        let json_data = json
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            salt,
+            data: json_data
+                .iter()
+                .map(|v| Answer::from_json(v, year))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl Answer {
+    /// Parse an answer of `year` from JSON.
+    pub(crate) fn from_json(value: &JsonValue, year: Year) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str_with_year(year, day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{
+        day,
+        template::{Day, Year},
+    };
+
+    use super::{Answer, Answers, Verification};
+
+    fn get_mock_answers() -> Answers {
+        Answers {
+            salt: None,
+            data: vec![
+                Answer {
+                    day: day!(1),
+                    part_1: Some("11".into()),
+                    part_2: Some("31".into()),
+                },
+                Answer {
+                    day: day!(3),
+                    part_1: Some("161".into()),
+                    part_2: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.verify(day!(1), 1, Some("11")), Verification::Pass);
+        assert_eq!(
+            answers.verify(day!(1), 2, Some("30")),
+            Verification::Fail {
+                expected: Some("31".into()),
+                actual: Some("30".into())
+            }
+        );
+        assert_eq!(
+            answers.verify(day!(3), 1, None),
+            Verification::Fail {
+                expected: Some("161".into()),
+                actual: None
+            }
+        );
+        assert_eq!(
+            answers.verify(day!(3), 2, Some("48")),
+            Verification::Missing
+        );
+        assert_eq!(answers.verify(day!(4), 1, Some("1")), Verification::Missing);
+    }
+
+    #[test]
+    fn sets_answers() {
+        let mut answers = get_mock_answers();
+        answers.set(day!(3), 2, "48");
+        answers.set(day!(2), 1, "2");
+        answers.set(day!(1), 1, "12");
+
+        assert_eq!(answers.data.len(), 3);
+        assert_eq!(answers.data[1].day, day!(2));
+        assert_eq!(answers.get(day!(3), 2), Some("48"));
+        assert_eq!(answers.get(day!(2), 1), Some("2"));
+        assert_eq!(answers.get(day!(1), 1), Some("12"));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let answers = get_mock_answers();
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Answers::from_json(&json, Year::current()).unwrap();
+        assert_eq!(parsed.data, answers.data);
+    }
+
+    #[test]
+    fn parses_days_of_given_year() {
+        let year = Year::new(2017).unwrap();
+        let json = r#"{ "data": [{ "day": "05", "part_1": "1", "part_2": null }] }"#;
+        let answers = Answers::from_json(json, year).unwrap();
+        assert_eq!(answers.get(Day::with_year(year, 5).unwrap(), 1), Some("1"));
+        assert_eq!(answers.get(day!(5), 1), None);
+    }
+
+    #[test]
+    fn verifies_hashed_answers() {
+        let answers = get_mock_answers().into_hashed();
+        assert!(answers.is_hashed());
+        assert_ne!(answers.get(day!(1), 1), Some("11"));
+        assert_eq!(answers.get(day!(3), 2), None);
+
+        assert_eq!(answers.verify(day!(1), 1, Some("11")), Verification::Pass);
+        assert_eq!(answers.verify(day!(1), 2, Some("31")), Verification::Pass);
+        assert_eq!(
+            answers.verify(day!(1), 2, Some("11")),
+            Verification::Fail {
+                expected: None,
+                actual: Some("11".into())
+            }
+        );
+        assert_eq!(
+            answers.verify(day!(3), 2, Some("48")),
+            Verification::Missing
+        );
+    }
+
+    #[test]
+    fn sets_hashed_answers() {
+        let mut answers = get_mock_answers().into_hashed();
+        answers.set(day!(3), 2, "48");
+        assert_ne!(answers.get(day!(3), 2), Some("48"));
+        assert_eq!(answers.verify(day!(3), 2, Some("48")), Verification::Pass);
+    }
+
+    #[test]
+    fn roundtrips_hashed_json() {
+        let answers = get_mock_answers().into_hashed();
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Answers::from_json(&json, Year::current()).unwrap();
+        assert_eq!(parsed.salt, answers.salt);
+        assert_eq!(parsed.verify(day!(1), 1, Some("11")), Verification::Pass);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "26", "part_1": null, "part_2": null }] }"#.to_string();
+        Answers::from_json(&json, Year::current()).unwrap();
+    }
+}
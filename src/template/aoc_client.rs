@@ -0,0 +1,616 @@
+/// Native client for the Advent of Code website.
+/// Downloads inputs and puzzle descriptions, submits answers and syncs progress with the user's session cookie.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{
+    get_data_path,
+    progress::{DayStars, Progress},
+    submission::Verdict,
+    Day, Year,
+};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/raoulkent/aoc-2024-rust";
+static SESSION_FILE_NAME: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    InvalidSession,
+    NotFound,
+    BadStatus(u16),
+    Transport(String),
+    MissingContent,
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or create \"~/{SESSION_FILE_NAME}\"."
+            ),
+            AocClientError::InvalidSession => {
+                write!(f, "the session cookie was rejected, it might have expired.")
+            }
+            AocClientError::NotFound => {
+                write!(
+                    f,
+                    "the puzzle could not be found, it might not be unlocked yet."
+                )
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "the request failed: {e}"),
+            AocClientError::MissingContent => {
+                write!(f, "the response did not contain the expected content.")
+            }
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(400, _) => AocClientError::InvalidSession,
+            ureq::Error::Status(404, _) => AocClientError::NotFound,
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a client from the environment.
+    /// The base url can be overridden with `AOC_BASE_URL`, e.g. to point at a local server.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the personal puzzle input for a day.
+    pub fn fetch_input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(day, "/input")
+    }
+
+    /// Fetches the puzzle page for a day and converts its description to markdown.
+    pub fn fetch_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(day, "")?;
+        articles_to_markdown(&html)
+    }
+
+    /// Submits an answer and classifies the response.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Verdict, AocClientError> {
+        let html = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        articles_to_markdown(&html).map(|message| Verdict::parse(&message))
+    }
+
+    /// Fetches the calendar of a year and reads the stars the user earned on every day.
+    pub fn fetch_progress(&self, year: Year) -> Result<Progress, AocClientError> {
+        let html = self.get_url(&format!("{}/{year}", self.base_url))?;
+        parse_calendar(&html, year)
+    }
+
+    fn get(&self, day: Day, path: &str) -> Result<String, AocClientError> {
+        self.get_url(&self.url(day, path))
+    }
+
+    fn get_url(&self, url: &str) -> Result<String, AocClientError> {
+        let body = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()?;
+
+        Ok(body)
+    }
+
+    /// The url of a page of a day's puzzle, in the year the day belongs to.
+    fn url(&self, day: Day, path: &str) -> String {
+        format!(
+            "{}/{}/day/{}{path}",
+            self.base_url,
+            day.year(),
+            day.into_inner()
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn read(day: Day) -> Result<(), AocClientError> {
+    let puzzle = AocClient::from_env()?.fetch_puzzle(day)?;
+    let puzzle_path = get_puzzle_path(day);
+    if let Some(dir) = Path::new(&puzzle_path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&puzzle_path, &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+pub fn download(day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    for path in [&input_path, &puzzle_path] {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    fs::write(&input_path, client.fetch_input(day)?)?;
+    fs::write(&puzzle_path, client.fetch_puzzle(day)?)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(day: Day, part: u8, result: &str) -> Result<Verdict, AocClientError> {
+    AocClient::from_env()?.submit(day, part, result)
+}
+
+pub fn fetch_progress(year: Year) -> Result<Progress, AocClientError> {
+    AocClient::from_env()?.fetch_progress(year)
+}
+
+fn get_input_path(day: Day) -> String {
+    get_data_path(day.year(), &format!("inputs/{day}.txt"))
+}
+
+fn get_puzzle_path(day: Day) -> String {
+    get_data_path(day.year(), &format!("puzzles/{day}.md"))
+}
+
+/// Reads the session cookie from `AOC_SESSION` or from the session file in the home directory.
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(PathBuf::from(home).join(SESSION_FILE_NAME)).ok()?;
+
+    if session.trim().is_empty() {
+        None
+    } else {
+        Some(session)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads the stars of every day from the links of a calendar page.
+/// Days with one star are marked with `calendar-complete`, days with two stars with `calendar-verycomplete`.
+fn parse_calendar(html: &str, year: Year) -> Result<Progress, AocClientError> {
+    let mut data: Vec<DayStars> = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<a ") {
+        rest = &rest[start..];
+        let end = rest.find('>').ok_or(AocClientError::MissingContent)?;
+        let tag = &rest[..end];
+        rest = &rest[end..];
+
+        let Some(day) = get_attribute(tag, "href")
+            .and_then(|href| {
+                href.strip_prefix(&format!("/{year}/day/"))?
+                    .parse::<u8>()
+                    .ok()
+            })
+            .and_then(|day| Day::with_year(year, day))
+        else {
+            continue;
+        };
+
+        let classes = get_attribute(tag, "class").unwrap_or_default();
+        let stars = if classes.contains("calendar-verycomplete") {
+            2
+        } else if classes.contains("calendar-complete") {
+            1
+        } else {
+            0
+        };
+
+        if !data.iter().any(|d| d.day == day) {
+            data.push(DayStars { day, stars });
+        }
+    }
+
+    // the calendar links to every unlocked day, a page without such links is not a calendar.
+    if data.is_empty() {
+        return Err(AocClientError::MissingContent);
+    }
+
+    data.sort_by_key(|d| d.day);
+    Ok(Progress { data })
+}
+
+/// Converts every `<article>` of a page to markdown.
+fn articles_to_markdown(html: &str) -> Result<String, AocClientError> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let content = &rest[start..];
+        let content = &content[content.find('>').ok_or(AocClientError::MissingContent)? + 1..];
+        let end = content
+            .find("</article>")
+            .ok_or(AocClientError::MissingContent)?;
+        articles.push(html_to_markdown(&content[..end]));
+        rest = &content[end..];
+    }
+
+    if articles.is_empty() {
+        return Err(AocClientError::MissingContent);
+    }
+
+    Ok(articles.join("\n\n"))
+}
+
+/// Converts the subset of HTML used on puzzle pages to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<Option<String>> = vec![];
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        push_text(&mut out, &rest[..tag_start], in_pre);
+
+        let Some(tag_len) = rest[tag_start..].find('>') else {
+            rest = "";
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("\n\n## "),
+            ("h2" | "p" | "ul", _) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("\n- "),
+            ("br", _) => out.push('\n'),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("\n\n```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("a", false) => {
+                let href = get_attribute(tag, "href");
+                if href.is_some() {
+                    out.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    out.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    push_text(&mut out, rest, in_pre);
+    normalize_markdown(&out)
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+    if in_pre {
+        out.push_str(&text);
+    } else {
+        out.push_str(&text.replace('\n', " "));
+    }
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + len]))
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code = entity.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Trims lines and collapses blank lines outside of code blocks.
+fn normalize_markdown(s: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+    let mut in_code_block = false;
+
+    for line in s.lines() {
+        if in_code_block {
+            in_code_block = line != "```";
+            lines.push(line);
+            continue;
+        }
+
+        let line = line.trim();
+        if line == "```" {
+            in_code_block = true;
+        }
+
+        if !line.is_empty() || lines.last().is_some_and(|l| !l.is_empty()) {
+            lines.push(line);
+        }
+    }
+
+    lines.join("\n").trim().to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{html_to_markdown, AocClient, AocClientError};
+    use crate::{
+        day,
+        template::{mock_server::MockServer, submission::Verdict, Day, Year},
+    };
+
+    static PUZZLE_PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>Throughout the Chief's office, the historically significant locations are listed <em>not by name</em> but by a unique number called the <em>location ID</em>.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+
+2   5
+</code></pre>
+<ul>
+<li>Pair up the <code>1</code> with the <code>3</code>.</li>
+<li>See the <a href="/2024/about">about page</a>.</li>
+</ul>
+<p>The total distance is <code><em>11</em></code> &amp; &lt;3&gt;.</p>
+</article>
+<p>To play, please identify yourself.</p>
+</main></body></html>"#;
+
+    #[test]
+    fn converts_html_to_markdown() {
+        let markdown = html_to_markdown(
+            PUZZLE_PAGE
+                .split_once("<article class=\"day-desc\">")
+                .unwrap()
+                .1
+                .split_once("</article>")
+                .unwrap()
+                .0,
+        );
+
+        let expected = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "Throughout the Chief's office, the historically significant locations are listed *not by name* but by a unique number called the *location ID*.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "",
+            "2   5",
+            "```",
+            "",
+            "- Pair up the `1` with the `3`.",
+            "- See the [about page](/2024/about).",
+            "",
+            "The total distance is `*11*` & <3>.",
+        ]
+        .join("\n");
+
+        assert_eq!(markdown, expected);
+    }
+
+    #[test]
+    fn fetches_input() {
+        let server = MockServer::start(vec![(200, "3   4\n4   3\n".into())]);
+        let client = AocClient::new(&server.url, "abc");
+
+        let input = client.fetch_input(day!(5)).unwrap();
+        assert_eq!(input, "3   4\n4   3\n");
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2024/day/5/input HTTP/1.1"));
+        assert!(requests[0].contains("session=abc"));
+    }
+
+    #[test]
+    fn fetches_puzzle_description() {
+        let server = MockServer::start(vec![(200, PUZZLE_PAGE.into())]);
+        let client = AocClient::new(&server.url, "abc");
+
+        let puzzle = client.fetch_puzzle(day!(1)).unwrap();
+        assert!(puzzle.starts_with("## --- Day 1: Historian Hysteria ---"));
+        assert!(!puzzle.contains("identify yourself"));
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2024/day/1 HTTP/1.1"));
+    }
+
+    #[test]
+    fn requests_the_year_of_the_day() {
+        let server = MockServer::start(vec![(200, "input".into())]);
+        let client = AocClient::new(&server.url, "abc");
+
+        let day = Day::with_year(Year::new(2017).unwrap(), 25).unwrap();
+        client.fetch_input(day).unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2017/day/25/input HTTP/1.1"));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let server = MockServer::start(vec![(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>".into(),
+        )]);
+        let client = AocClient::new(&server.url, "abc");
+
+        let verdict = client.submit(day!(5), 2, "143").unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2024/day/5/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=143"));
+    }
+
+    #[test]
+    fn maps_error_statuses() {
+        let server = MockServer::start(vec![
+            (400, "Puzzle inputs differ by user.".into()),
+            (404, "Not found".into()),
+            (500, "Internal error".into()),
+        ]);
+        let client = AocClient::new(&server.url, "abc");
+
+        assert!(matches!(
+            client.fetch_input(day!(1)),
+            Err(AocClientError::InvalidSession)
+        ));
+        assert!(matches!(
+            client.fetch_input(day!(2)),
+            Err(AocClientError::NotFound)
+        ));
+        assert!(matches!(
+            client.fetch_input(day!(3)),
+            Err(AocClientError::BadStatus(500))
+        ));
+    }
+
+    #[test]
+    fn fetches_progress() {
+        let calendar = r#"<main><pre class="calendar">
+<a aria-label="Day 1, two stars" href="/2017/day/1" class="calendar-day1 calendar-verycomplete">1 **</a>
+<a aria-label="Day 2, one star" href="/2017/day/2" class="calendar-day2 calendar-complete">2 *</a>
+<a aria-label="Day 3" href="/2017/day/3" class="calendar-day3">3</a>
+<a href="/2017/about">About</a>
+</pre></main>"#;
+        let server = MockServer::start(vec![(200, calendar.into())]);
+        let client = AocClient::new(&server.url, "abc");
+
+        let year = Year::new(2017).unwrap();
+        let progress = client.fetch_progress(year).unwrap();
+        let stars: Vec<(u8, u8)> = progress
+            .data
+            .iter()
+            .map(|d| (d.day.into_inner(), d.stars))
+            .collect();
+        assert_eq!(stars, vec![(1, 2), (2, 1), (3, 0)]);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2017 HTTP/1.1"));
+        assert!(requests[0].contains("session=abc"));
+    }
+
+    #[test]
+    fn errors_on_missing_article() {
+        let server = MockServer::start(vec![(200, "<main></main>".into())]);
+        let client = AocClient::new(&server.url, "abc");
+
+        assert!(matches!(
+            client.fetch_puzzle(day!(1)),
+            Err(AocClientError::MissingContent)
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+/// Benchmarks a git ref in a temporary worktree, so it can be compared with the working copy.
+/// The worktree shares the puzzle inputs of the working copy and builds into `target/baseline`,
+/// which keeps compiled dependencies between comparisons.
+use std::{
+    collections::HashSet,
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{self, Command},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{
+    get_data_path,
+    run_multi::{self, run_multi, Workspace},
+    timings::Timings,
+    Day, Year,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Git(String),
+    IO(io::Error),
+    Run(run_multi::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<run_multi::Error> for Error {
+    fn from(e: run_multi::Error) -> Self {
+        Error::Run(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Git(message) => write!(f, "git failed: {message}"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Run(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A detached git worktree that is removed when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    /// Add a worktree in a new directory. Registrations of worktrees whose directory is gone,
+    /// e.g. after an interrupted run, are pruned first.
+    fn create(git_ref: &str) -> Result<Self, Error> {
+        git(&["worktree", "prune"])?;
+
+        let path = create_unique_dir()?;
+        if let Err(e) = git(&[
+            "worktree",
+            "add",
+            "--detach",
+            &path.to_string_lossy(),
+            git_ref,
+        ]) {
+            let _ = fs::remove_dir_all(&path);
+            return Err(e);
+        }
+
+        Ok(Worktree { path })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        if let Err(e) = git(&[
+            "worktree",
+            "remove",
+            "--force",
+            &self.path.to_string_lossy(),
+        ]) {
+            eprintln!("Failed to remove baseline worktree: {e}");
+        }
+    }
+}
+
+/// Create an empty directory in the temp dir that no other run uses, not even a stale one of an earlier process with the same id.
+fn create_unique_dir() -> Result<PathBuf, Error> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+
+    for attempt in 0_u32.. {
+        let name = format!("aoc-baseline-{}-{nanos:x}-{attempt}", process::id());
+        let path = env::temp_dir().join(name);
+
+        match fs::create_dir(&path) {
+            Ok(()) => return Ok(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    unreachable!("ran out of attempts to create a unique directory")
+}
+
+fn git(args: &[&str]) -> Result<(), Error> {
+    let output = Command::new("git").args(args).output()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(Error::Git(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+/// Copy the puzzle inputs of the working copy, which are usually not committed.
+fn copy_inputs(to: &Path) -> Result<(), Error> {
+    let inputs = get_data_path(Year::current(), "inputs");
+    let target = to.join(&inputs);
+    fs::create_dir_all(&target)?;
+
+    for entry in fs::read_dir(&inputs)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            fs::copy(entry.path(), target.join(entry.file_name()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Benchmark a set of days at `git_ref`. The ref needs to include the report protocol of `run_multi` and the per-year layout.
+pub fn run_baseline(
+    git_ref: &str,
+    days_to_run: &HashSet<Day>,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+) -> Result<Timings, Error> {
+    let worktree = Worktree::create(git_ref)?;
+    copy_inputs(&worktree.path)?;
+
+    // a relative target directory would resolve against the worktree, so it is made absolute.
+    let target_dir = env::current_dir()?
+        .join(env::var_os("CARGO_TARGET_DIR").unwrap_or_else(|| "target".into()))
+        .join("baseline");
+
+    let workspace = Workspace {
+        root: Some(worktree.path.clone()),
+        target_dir: Some(target_dir),
+    };
+
+    let timings = run_multi(days_to_run, true, true, 1, part, timeout, &workspace)?
+        .timings
+        .unwrap();
+
+    Ok(timings)
+}
@@ -1,5 +1,54 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    commands::Failure,
+    registry,
+    run_multi::{run_in_process, run_multi, Workspace},
+};
+
+/// Run all days. Fails if a day panicked, exited with an error or timed out.
+/// `--timeout` is a usage error for in-process runs, as a day can't be killed without killing the others.
+pub fn handle(
+    is_release: bool,
+    in_process: bool,
+    jobs: usize,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+) -> Result<(), Failure> {
+    if in_process && timeout.is_some() {
+        return Err(Failure::Usage(
+            "`--timeout` can't be combined with `--in-process`, in-process runs can't be interrupted"
+                .into(),
+        ));
+    }
+
+    let run = if in_process {
+        if jobs > 1 {
+            eprintln!("Ignoring `--jobs`, in-process runs are serial.");
+        }
+
+        registry::update()
+            .map_err(|e| Failure::Other(format!("failed to update registry of days: {e}")))?;
+
+        run_in_process(is_release, part)?
+    } else {
+        run_multi(
+            &all_days().collect(),
+            is_release,
+            false,
+            jobs,
+            part,
+            timeout,
+            &Workspace::default(),
+        )?
+    };
+
+    let crashed = run.crashed_days();
+
+    if crashed.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::Crashed(crashed))
+    }
 }
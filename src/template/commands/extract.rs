@@ -0,0 +1,130 @@
+use std::{fs, process};
+
+use crate::template::{
+    get_data_path,
+    manifest::Manifest,
+    puzzle::{guess_example, parse_answers, parse_code_blocks},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Write the example of a part to the examples directory and record its expected answers in the manifest.
+/// The example of part one is written to `DD.txt`, the example of part two to `DD-2.txt`.
+pub fn handle(day: Day, block: Option<usize>, part: u8, list: bool, overwrite: bool) {
+    let puzzle_path = get_data_path(day.year(), &format!("puzzles/{day}.md"));
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Run `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    let blocks = parse_code_blocks(&puzzle);
+    if blocks.is_empty() {
+        eprintln!("The puzzle description does not contain any code blocks.");
+        process::exit(1);
+    }
+
+    let guessed = guess_example(&blocks, part);
+
+    println!("{ANSI_BOLD}Code blocks{ANSI_RESET}");
+    println!("------");
+    for (i, block) in blocks.iter().enumerate() {
+        let hint = if guessed == Some(i) {
+            " ← example"
+        } else {
+            ""
+        };
+        println!(
+            "[{}] Part {}: {} {ANSI_ITALIC}({} lines){ANSI_RESET}{hint}",
+            i + 1,
+            block.part,
+            block.preview(),
+            block.content.lines().count()
+        );
+    }
+
+    if list {
+        return;
+    }
+
+    // blocks are numbered from 1 when listed.
+    let index = match block {
+        Some(n) if (1..=blocks.len()).contains(&n) => n - 1,
+        Some(n) => {
+            eprintln!(
+                "There is no code block {n}, expecting a value between 1 and {}.",
+                blocks.len()
+            );
+            process::exit(1);
+        }
+        None => match guessed {
+            Some(i) => i,
+            None => {
+                eprintln!("Could not guess the example of part {part}. Pick a code block with `--block <n>`.");
+                process::exit(1);
+            }
+        },
+    };
+
+    let file = match part {
+        1 => format!("{day}.txt"),
+        _ => format!("{day}-{part}.txt"),
+    };
+    let example_path = get_data_path(day.year(), &format!("examples/{file}"));
+
+    println!();
+
+    let is_empty = fs::read_to_string(&example_path).map_or(true, |s| s.trim().is_empty());
+    if is_empty || overwrite {
+        let written = fs::create_dir_all(get_data_path(day.year(), "examples"))
+            .and_then(|()| fs::write(&example_path, &blocks[index].content));
+
+        if let Err(e) = written {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+        println!("Wrote code block {} to \"{example_path}\".", index + 1);
+    } else {
+        println!("Kept existing example \"{example_path}\", use `--overwrite` to replace it.");
+    }
+
+    // part two usually reuses the example of part one, unless its description introduces a new one.
+    let has_own_example = blocks.iter().any(|b| b.part == 2 && b.is_example);
+    let parts: &[u8] = match part {
+        1 if has_own_example => &[1],
+        1 => &[1, 2],
+        _ => &[2],
+    };
+
+    let answers = parse_answers(&puzzle);
+    let mut manifest = match Manifest::read_from_file(day) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("Failed to read example manifest {e}");
+            process::exit(1);
+        }
+    };
+
+    for &part in parts {
+        match &answers[usize::from(part - 1)] {
+            Some(answer) => {
+                manifest.set_answer(&file, part, answer.clone());
+                println!("Part {part}: expecting {ANSI_BOLD}{answer}{ANSI_RESET}");
+            }
+            None => println!("Part {part}: no expected answer found."),
+        }
+    }
+
+    if part == 1 && has_own_example {
+        println!("Part two has its own example, run `cargo extract {day} --part 2` to add it.");
+    }
+
+    if let Err(e) = manifest.store_file(day) {
+        eprintln!("Failed to store example manifest: {e}");
+        process::exit(1);
+    }
+
+    println!(
+        "Stored expected answers in \"{}\".",
+        Manifest::get_path(day)
+    );
+}
@@ -1,6 +1,113 @@
 pub mod all;
 pub mod download;
+pub mod extract;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod status;
 pub mod time;
+pub mod verify;
+pub mod watch;
+
+use std::fmt::Display;
+
+use crate::template::{run_multi, Day};
+
+/// Reason for a command to fail. `main` exits with a distinct status for every kind of failure, so scripts can tell them apart.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// Something else went wrong, e.g. a file could not be written.
+    Other(String),
+    /// The command-line arguments could not be parsed.
+    Usage(String),
+    /// Solutions panicked, exited with an error or timed out.
+    Crashed(Vec<Day>),
+    /// Solutions did not return an answer for parts that have a stored answer.
+    MissingOutput(Vec<(Day, u8)>),
+    /// Solutions returned answers that differ from the stored answers.
+    Mismatch(Vec<(Day, u8)>),
+}
+
+impl Failure {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Failure::Other(_) => 1,
+            Failure::Usage(_) => 2,
+            Failure::Crashed(_) => 3,
+            Failure::MissingOutput(_) => 4,
+            Failure::Mismatch(_) => 5,
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Other(message) | Failure::Usage(message) => write!(f, "{message}"),
+            Failure::Crashed(days) if days.is_empty() => write!(f, "a solution crashed."),
+            Failure::Crashed(days) => {
+                let days: Vec<String> = days.iter().map(|day| format!("Day {day}")).collect();
+                write!(f, "solutions crashed or timed out: {}.", days.join(", "))
+            }
+            Failure::MissingOutput(parts) => {
+                write!(f, "solutions returned no answer: {}.", format_parts(parts))
+            }
+            Failure::Mismatch(parts) => {
+                write!(f, "answers do not match: {}.", format_parts(parts))
+            }
+        }
+    }
+}
+
+impl From<pico_args::Error> for Failure {
+    fn from(e: pico_args::Error) -> Self {
+        Failure::Usage(e.to_string())
+    }
+}
+
+impl From<run_multi::Error> for Failure {
+    fn from(e: run_multi::Error) -> Self {
+        Failure::Other(e.to_string())
+    }
+}
+
+fn format_parts(parts: &[(Day, u8)]) -> String {
+    parts
+        .iter()
+        .map(|(day, part)| format!("Day {day} Part {part}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Failure;
+    use crate::day;
+
+    #[test]
+    fn uses_distinct_exit_codes() {
+        let failures = [
+            Failure::Other(String::new()),
+            Failure::Usage(String::new()),
+            Failure::Crashed(vec![]),
+            Failure::MissingOutput(vec![]),
+            Failure::Mismatch(vec![]),
+        ];
+
+        let codes: HashSet<i32> = failures.iter().map(Failure::exit_code).collect();
+        assert_eq!(codes.len(), failures.len());
+        assert!(!codes.contains(&0));
+    }
+
+    #[test]
+    fn formats_failures() {
+        let failure = Failure::Mismatch(vec![(day!(1), 2), (day!(3), 1)]);
+        assert_eq!(
+            failure.to_string(),
+            "answers do not match: Day 01 Part 2, Day 03 Part 1."
+        );
+    }
+}
@@ -1,10 +1,19 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{
+    get_data_path,
+    manifest::Manifest,
+    registry,
+    run_multi::{get_bin_name, get_path_for_bin},
+    Day, Year,
+};
+
+static MANIFEST_FILE_PATH: &str = "Cargo.toml";
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -16,21 +25,96 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     } else {
         file.create_new(true);
     }
+    create_parent_dir(path)?;
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_parent_dir(path: &str) -> Result<(), io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+/// Bins in per-year directories are not discovered by cargo, so every day is declared in the manifest.
+fn register_bin(day: Day) -> Result<bool, io::Error> {
+    let manifest = fs::read_to_string(MANIFEST_FILE_PATH)?;
+    let name = get_bin_name(day);
+
+    if manifest.contains(&format!("name = \"{name}\"")) {
+        return Ok(false);
+    }
+
+    let path = get_path_for_bin(day);
+    let entry = format!(
+        "\n[[bin]]\nname = \"{name}\"\npath = \"{}\"\n",
+        path.trim_start_matches("./")
+    );
+
     OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+        .append(true)
+        .open(MANIFEST_FILE_PATH)?
+        .write_all(entry.as_bytes())?;
+
+    Ok(true)
+}
+
+/// Create an empty file, keeping an existing file as is. Returns `false` if the file already existed.
+fn create_empty_file(path: &str) -> Result<bool, std::io::Error> {
+    create_parent_dir(path)?;
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Fill the template. If the example manifest of the day has expected answers, the tests assert them.
+fn render_module(day: Day, manifest: &Manifest) -> String {
+    let mut module = MODULE_TEMPLATE.replace("%DAY_NUMBER%", &day.into_inner().to_string());
+
+    for part in 1..=2 {
+        let mut example = "advent_of_code::template::read_file(\"examples\", DAY)".to_string();
+        let mut expected = "None".to_string();
+
+        // the template returns numbers, other answers need to be asserted by hand.
+        let answered = manifest.get_answered(part).and_then(|e| {
+            let answer = e.answer.as_deref()?.parse::<u64>().ok()?;
+            Some((e, e.file_suffix(day)?, answer))
+        });
+
+        if let Some((e, suffix, answer)) = answered {
+            // examples with puzzle parameters are read through the manifest, which also applies the parameters.
+            if !e.params.is_empty() {
+                example = format!("advent_of_code::template::params::read_example(DAY, {part})");
+            } else if let Some(suffix) = suffix {
+                example = format!(
+                    "advent_of_code::template::read_file_part(\"examples\", DAY, {suffix})"
+                );
+            }
+            expected = format!("Some({answer})");
+        }
+
+        module = module
+            .replace(&format!("%PART_{part}_EXAMPLE%"), &example)
+            .replace(&format!("%PART_{part}_EXPECTED%"), &expected);
+    }
+
+    module
 }
 
 pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let input_path = get_data_path(day.year(), &format!("inputs/{day}.txt"));
+    let example_path = get_data_path(day.year(), &format!("examples/{day}.txt"));
+    let module_path = get_path_for_bin(day);
+
+    let manifest = match Manifest::read_from_file(day) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("Failed to read example manifest {e}");
+            process::exit(1);
+        }
+    };
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +124,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(render_module(day, &manifest).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,20 +134,42 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
+    match register_bin(day) {
+        Ok(true) => println!(
+            "Added bin \"{}\" to \"{MANIFEST_FILE_PATH}\"",
+            get_bin_name(day)
+        ),
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to add bin to manifest: {e}");
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = registry::update() {
+        eprintln!("Failed to update registry of days: {e}");
+    }
+
+    match create_empty_file(&input_path) {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
+    match create_empty_file(&example_path) {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", &example_path);
+        }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
@@ -75,5 +177,12 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if day.year() == Year::current() {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!(
+            "🎄 Type `cargo solve {day} --year {}` to run your solution.",
+            day.year()
+        );
+    }
 }
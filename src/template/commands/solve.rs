@@ -1,9 +1,33 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Stdio};
 
-use crate::template::Day;
+use crate::template::{
+    run_multi::{cargo_command, get_bin_name},
+    Day,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// An input to run a solution against instead of the puzzle input.
+pub enum Input {
+    /// A file, or stdin if the path is `-`.
+    Path(String),
+    /// The example of the day, or one of its numbered variants (e.g. `01-2.txt`).
+    Example(Option<u8>),
+}
+
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    input: Option<Input>,
+    part: Option<u8>,
+    params: Vec<String>,
+) {
+    if let Err(e) = check_submit(submit_part, input.as_ref(), &params) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), get_bin_name(day)];
 
     if dhat {
         cmd_args.extend([
@@ -23,7 +47,29 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    for param in params {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(param);
+    }
+
+    match input {
+        Some(Input::Path(path)) => {
+            cmd_args.push("--input".to_string());
+            cmd_args.push(path);
+        }
+        Some(Input::Example(part)) => {
+            cmd_args.push("--example".to_string());
+            cmd_args.extend(part.map(|part| part.to_string()));
+        }
+        None => {}
+    }
+
+    let mut cmd = cargo_command()
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -32,3 +78,40 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// `--submit` is only allowed for the puzzle input with the default parameters of the solution.
+/// Answers for other inputs or parameters would be rejected by the website and count towards the submission timeout.
+fn check_submit(
+    submit_part: Option<u8>,
+    input: Option<&Input>,
+    params: &[String],
+) -> Result<(), &'static str> {
+    if submit_part.is_none() {
+        return Ok(());
+    }
+
+    match input {
+        Some(Input::Path(_)) => Err("`--submit` can only be used with the puzzle input, not with `--input`."),
+        Some(Input::Example(_)) => Err("`--submit` can only be used with the puzzle input, not with `--example`."),
+        None if !params.is_empty() => Err("`--submit` can't be used with `--param`, the answer would not be for the puzzle input."),
+        None => Ok(()),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_submit, Input};
+
+    #[test]
+    fn allows_submitting_the_puzzle_input() {
+        assert!(check_submit(Some(1), None, &[]).is_ok());
+        assert!(check_submit(None, Some(&Input::Example(None)), &["size=7".into()]).is_ok());
+    }
+
+    #[test]
+    fn rejects_submitting_other_inputs_or_params() {
+        assert!(check_submit(Some(1), Some(&Input::Path("-".into())), &[]).is_err());
+        assert!(check_submit(Some(1), Some(&Input::Example(Some(2))), &[]).is_err());
+        assert!(check_submit(Some(2), None, &["size=7".into()]).is_err());
+    }
+}
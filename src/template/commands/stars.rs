@@ -0,0 +1,48 @@
+use std::process;
+
+use crate::template::{
+    all_days, answers::Answers, aoc_client, progress::Progress, readme_stars,
+    submission::SubmissionHistory, Year,
+};
+
+/// Write the stars table to the readme.
+/// Stars are derived from the stored answers and submissions, `--sync` also fetches them from the AoC calendar.
+pub fn handle(sync: bool) {
+    let year = Year::current();
+    let mut synced = Progress::read_from_file(year);
+
+    if sync {
+        synced = match aoc_client::fetch_progress(year) {
+            Ok(progress) => progress,
+            Err(e) => {
+                eprintln!("failed to sync progress: {e}");
+                process::exit(1);
+            }
+        };
+
+        if let Err(e) = synced.store_file(year) {
+            eprintln!("Failed to store synced progress: {e}");
+            process::exit(1);
+        }
+
+        println!(
+            "Synced {} stars from the calendar of {year}.",
+            synced.total_stars()
+        );
+    }
+
+    let progress = Progress::from_local(
+        all_days(),
+        &Answers::read_from_file(year),
+        &SubmissionHistory::read_from_file(year),
+    )
+    .merge(&synced);
+
+    match readme_stars::update(year, &progress) {
+        Ok(()) => println!("Stored {} stars in the readme.", progress.total_stars()),
+        Err(_) => {
+            eprintln!("Failed to store stars in the readme.");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::fs;
+
+use crate::template::{
+    all_days,
+    answers::Answers,
+    get_data_path,
+    lint::find_stub_parts,
+    puzzle::has_part_two,
+    run_multi::{cargo_command, get_bin_name, get_path_for_bin},
+    timings::Timings,
+    watcher::parse_test_output,
+    Day, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Print an overview of every day: its bin, input, puzzle description, example tests, stored answers and timings.
+/// The example tests build and run every scaffolded day, so they only run with `--tests`.
+pub fn handle(tests: bool) {
+    let year = Year::current();
+    let answers = Answers::read_from_file(year);
+    let timings = Timings::read_from_file(year);
+
+    println!("{ANSI_BOLD}Status {year}{ANSI_RESET}");
+    println!("------");
+    println!(
+        "{:<4} {:<7} {:<6} {:<8} {:<8} {:<14} Timings",
+        "Day", "Bin", "Input", "Puzzle", "Tests", "Stored answers"
+    );
+
+    for day in all_days() {
+        let source = fs::read_to_string(get_path_for_bin(day)).ok();
+
+        let bin = match &source {
+            None => "-",
+            Some(source) if !find_stub_parts(source).is_empty() => "stub",
+            Some(_) => "✓",
+        };
+
+        let input = if is_non_empty(&get_data_path(day.year(), &format!("inputs/{day}.txt"))) {
+            "✓"
+        } else {
+            "-"
+        };
+
+        let puzzle = match fs::read_to_string(get_puzzle_path(day)) {
+            Ok(puzzle) if has_part_two(&puzzle) => "part 2",
+            Ok(_) => "part 1",
+            Err(_) => "-",
+        };
+
+        let tests = match (&source, tests) {
+            (Some(_), true) => run_tests(day),
+            _ => "-".to_string(),
+        };
+
+        // answers are not checked here, see `cargo verify`.
+        let stored = (1..=2)
+            .map(|part| {
+                if answers.get(day, part).is_some() {
+                    "✓"
+                } else {
+                    "-"
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let timing = timings.data.iter().find(|t| t.day == day).map_or_else(
+            || "-".to_string(),
+            |t| {
+                format!(
+                    "{} / {}",
+                    t.part_1.as_deref().unwrap_or("-"),
+                    t.part_2.as_deref().unwrap_or("-")
+                )
+            },
+        );
+
+        let day = day.to_string();
+        println!("{day:<4} {bin:<7} {input:<6} {puzzle:<8} {tests:<8} {stored:<14} {timing}");
+    }
+}
+
+/// Run the example tests of a day. Returns the number of passed tests, or `✖ build` if the bin did not compile.
+fn run_tests(day: Day) -> String {
+    let Ok(output) = cargo_command()
+        .args(["test", "--bin", &get_bin_name(day)])
+        .output()
+    else {
+        return "✖ build".into();
+    };
+
+    let results = parse_test_output(&String::from_utf8_lossy(&output.stdout));
+
+    if results.is_empty() {
+        return if output.status.success() {
+            "-".into()
+        } else {
+            "✖ build".into()
+        };
+    }
+
+    let passed = results.iter().filter(|r| r.passed).count();
+    let mark = if passed == results.len() {
+        "✓"
+    } else {
+        "✖"
+    };
+
+    format!("{mark} {passed}/{}", results.len())
+}
+
+fn get_puzzle_path(day: Day) -> String {
+    get_data_path(day.year(), &format!("puzzles/{day}.md"))
+}
+
+fn is_non_empty(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
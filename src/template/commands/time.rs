@@ -1,11 +1,35 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
-use crate::template::run_multi::run_multi;
+use crate::template::baseline::run_baseline;
+use crate::template::commands::Failure;
+use crate::template::history::{Delta, RunInfo};
+use crate::template::run_multi::{run_multi, Workspace};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme_benchmarks, Day, Year, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Benchmark days and optionally store their timings.
+/// Fails if a day panicked, exited with an error or timed out. The timings of the other days are stored regardless.
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    threshold: f64,
+    baseline: Option<String>,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+) -> Result<(), Failure> {
+    if let Some(git_ref) = baseline {
+        if store {
+            eprintln!("Ignoring `--store`, timings are not stored when comparing with a baseline.");
+        }
+
+        // a baseline comparison always covers all days, unless a day is given.
+        let days_to_run = day.map_or_else(|| all_days().collect(), |day| HashSet::from([day]));
+        return compare_with_baseline(&git_ref, &days_to_run, part, timeout);
+    }
+
+    let year = Year::current();
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
@@ -21,11 +45,32 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run = run_multi(
+        &days_to_run,
+        true,
+        true,
+        1,
+        part,
+        timeout,
+        &Workspace::default(),
+    )?;
+    let crashed = run.crashed_days();
+
+    let mut timings = run.timings.unwrap();
+    timings.record_history(&RunInfo::current(true));
+
+    print_deltas(&timings.compare(&stored_timings), threshold);
 
     if store {
+        if part.is_some() {
+            // keep the stored timings of the parts that were not run.
+            timings.fill_from(&stored_timings);
+        }
+
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings
+            .store_file(year)
+            .map_err(|e| Failure::Other(format!("failed to store timings: {e}")))?;
 
         println!();
         match readme_benchmarks::update(merged_timings) {
@@ -37,4 +82,119 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if crashed.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::Crashed(crashed))
+    }
+}
+
+/// Print the change of every part against its previous run. Slowdowns above `threshold` percent are highlighted.
+fn print_deltas(deltas: &[Delta], threshold: f64) {
+    if deltas.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Compared to previous run{ANSI_RESET}");
+    println!("------");
+
+    for delta in deltas {
+        let part = match delta.part {
+            0 => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        let change = format!(
+            "{:.1?} → {:.1?} ({:+.1}%)",
+            delta.previous,
+            delta.current,
+            delta.percent()
+        );
+
+        if delta.is_regression(threshold) {
+            println!(
+                "Day {} {part}: {ANSI_BOLD}{change} ▲ regression{ANSI_RESET}",
+                delta.day
+            );
+        } else {
+            println!("Day {} {part}: {change}", delta.day);
+        }
+    }
+
+    let regressions = deltas.iter().filter(|d| d.is_regression(threshold)).count();
+    if regressions > 0 {
+        println!();
+        println!("{regressions} part(s) got more than {threshold}% slower.");
+    }
+}
+
+/// Benchmark `git_ref` and the working copy, then print a table of both timings.
+fn compare_with_baseline(
+    git_ref: &str,
+    days_to_run: &HashSet<Day>,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+) -> Result<(), Failure> {
+    println!("{ANSI_BOLD}Baseline ({git_ref}){ANSI_RESET}");
+    println!();
+
+    let baseline = run_baseline(git_ref, days_to_run, part, timeout)
+        .map_err(|e| Failure::Other(format!("failed to benchmark baseline: {e}")))?;
+
+    println!();
+    println!("{ANSI_BOLD}Working copy{ANSI_RESET}");
+    println!();
+
+    let run = run_multi(
+        days_to_run,
+        true,
+        true,
+        1,
+        part,
+        timeout,
+        &Workspace::default(),
+    )?;
+    let crashed = run.crashed_days();
+    let deltas = run.timings.unwrap().compare(&baseline);
+
+    println!();
+    println!("{ANSI_BOLD}Comparison{ANSI_RESET}");
+    println!("------");
+
+    if deltas.is_empty() {
+        println!("No parts were benchmarked in both versions.");
+    } else {
+        print_comparison(&deltas);
+    }
+
+    if crashed.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::Crashed(crashed))
+    }
+}
+
+fn print_comparison(deltas: &[Delta]) {
+    println!(
+        "{:<6} {:<6} {:>12} {:>12} {:>8}",
+        "Day", "Part", "Baseline", "Current", "Speedup"
+    );
+
+    for delta in deltas {
+        let part = match delta.part {
+            0 => "Parse".to_string(),
+            part => part.to_string(),
+        };
+
+        println!(
+            "{:<6} {:<6} {:>12} {:>12} {:>7.2}x",
+            delta.day.to_string(),
+            part,
+            format!("{:.1?}", delta.previous),
+            format!("{:.1?}", delta.current),
+            delta.speedup()
+        );
+    }
 }
@@ -0,0 +1,109 @@
+use std::{collections::HashSet, path::Path};
+
+use crate::template::answers::{Answers, Verification};
+use crate::template::commands::Failure;
+use crate::template::lint::lint;
+use crate::template::run_multi::{get_path_for_bin, run_multi, Workspace};
+use crate::template::{all_days, Day, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Run all scaffolded days and compare their answers with the stored answers.
+/// Fails if a day crashed, an answer does not match or a day printed no answer for a part with a stored answer.
+pub fn handle(is_release: bool, hash: bool) -> Result<(), Failure> {
+    let year = Year::current();
+    let mut expected = Answers::read_from_file(year);
+
+    if hash && !expected.is_hashed() {
+        expected = expected.into_hashed();
+        expected
+            .store_file(year)
+            .map_err(|e| Failure::Other(format!("failed to store hashed answers: {e}")))?;
+        println!("Replaced stored answers with salted hashes.");
+    }
+
+    // only run days that have been scaffolded.
+    let days_to_run: HashSet<Day> = all_days()
+        .filter(|day| Path::new(&get_path_for_bin(*day)).exists())
+        .collect();
+
+    let run = run_multi(
+        &days_to_run,
+        is_release,
+        false,
+        1,
+        None,
+        None,
+        &Workspace::default(),
+    )?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let (mut mismatches, mut missing_output) = (vec![], vec![]);
+
+    println!();
+    println!("{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!("------");
+
+    for day in all_days().filter(|day| days_to_run.contains(day)) {
+        let actual = run.answers.data.iter().find(|a| a.day == day);
+
+        for part in 1..=2 {
+            let verification = expected.verify(day, part, actual.and_then(|a| a.get(part)));
+
+            let status = match verification {
+                Verification::Pass => {
+                    passed += 1;
+                    "✓ pass".to_string()
+                }
+                Verification::Fail { expected, actual } => {
+                    failed += 1;
+                    if actual.is_some() {
+                        mismatches.push((day, part));
+                    } else {
+                        missing_output.push((day, part));
+                    }
+                    let actual = actual.unwrap_or_else(|| "-".into());
+                    match expected {
+                        Some(expected) => format!("✖ fail (expected `{expected}`, got `{actual}`)"),
+                        None => format!("✖ fail (got `{actual}`)"),
+                    }
+                }
+                Verification::Missing => {
+                    missing += 1;
+                    "- missing".to_string()
+                }
+            };
+
+            println!("Day {day} Part {part}: {status}");
+        }
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Total:{ANSI_RESET} {passed} passed, {failed} failed, {missing} missing.");
+
+    let warnings = lint(
+        all_days().filter(|day| days_to_run.contains(day)),
+        &expected,
+    );
+    if !warnings.is_empty() {
+        println!();
+        println!("{ANSI_BOLD}Warnings{ANSI_RESET}");
+        println!("------");
+
+        for warning in warnings {
+            println!(
+                "Day {} Part {}: solved, but its example test still asserts `None`.",
+                warning.day, warning.part
+            );
+        }
+    }
+
+    let crashed = run.crashed_days();
+    if !crashed.is_empty() {
+        Err(Failure::Crashed(crashed))
+    } else if !mismatches.is_empty() {
+        Err(Failure::Mismatch(mismatches))
+    } else if !missing_output.is_empty() {
+        Err(Failure::MissingOutput(missing_output))
+    } else {
+        Ok(())
+    }
+}
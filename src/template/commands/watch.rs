@@ -0,0 +1,152 @@
+use std::{
+    path::{Path, PathBuf},
+    process::{self, Output},
+    thread,
+    time::Duration,
+};
+
+use crate::template::{
+    get_data_path,
+    run_multi::{cargo_command, child_commands, get_bin_name, get_path_for_bin, Workspace},
+    watcher::{parse_test_output, Watcher},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+pub fn handle(day: Day) {
+    if !Path::new(&get_path_for_bin(day)).exists() {
+        eprintln!("Day {day} has not been scaffolded yet. Run `cargo scaffold {day}` first.");
+        process::exit(1);
+    }
+
+    let mut watcher = Watcher::new(get_watched_paths(day));
+
+    loop {
+        print!("{ANSI_CLEAR}");
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET} {ANSI_ITALIC}(watching for changes, press Ctrl-C to stop){ANSI_RESET}");
+        println!();
+
+        if run_tests(day) {
+            run_solution(day);
+        }
+
+        while !watcher.poll() {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// The solution, the shared library code, and the examples and input of the day.
+fn get_watched_paths(day: Day) -> Vec<PathBuf> {
+    let mut paths = vec![
+        PathBuf::from(get_path_for_bin(day)),
+        PathBuf::from("src/lib.rs"),
+        PathBuf::from(get_data_path(day.year(), &format!("inputs/{day}.txt"))),
+        PathBuf::from(get_data_path(day.year(), &format!("examples/{day}.txt"))),
+    ];
+
+    for part in 1..=2 {
+        paths.push(PathBuf::from(get_data_path(
+            day.year(),
+            &format!("examples/{day}-{part}.txt"),
+        )));
+    }
+
+    paths
+}
+
+/// Run the example tests of the day and print a line per test. Returns `false` if the bin did not compile.
+fn run_tests(day: Day) -> bool {
+    println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+    println!("------");
+
+    let output = match cargo_command()
+        .args(["test", "--bin", &get_bin_name(day)])
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            return false;
+        }
+    };
+
+    let results = parse_test_output(&String::from_utf8_lossy(&output.stdout));
+
+    if results.is_empty() {
+        if output.status.success() {
+            println!("No tests found.");
+        } else {
+            print_stderr(&output);
+            return false;
+        }
+    }
+
+    for result in &results {
+        if result.passed {
+            println!("✓ {}", result.name);
+        } else {
+            println!("✖ {}", result.name);
+        }
+    }
+
+    let failed = results.iter().filter(|r| !r.passed).count();
+    println!();
+    println!(
+        "{ANSI_BOLD}Total:{ANSI_RESET} {} passed, {failed} failed.",
+        results.len() - failed
+    );
+
+    true
+}
+
+/// Run the solution against the puzzle input and print its answers.
+fn run_solution(day: Day) {
+    println!();
+    println!("{ANSI_BOLD}Answers{ANSI_RESET}");
+    println!("------");
+
+    let input_path = get_data_path(day.year(), &format!("inputs/{day}.txt"));
+    if !Path::new(&input_path).exists() {
+        println!("No input in \"{input_path}\". Run `cargo download {day}` to fetch it.");
+        return;
+    }
+
+    let captured = match child_commands::run_solution_captured(
+        day,
+        false,
+        false,
+        None,
+        None,
+        &Workspace::default(),
+    ) {
+        Ok(captured) => captured,
+        Err(e) => {
+            eprintln!("Failed to run solution: {e:?}");
+            return;
+        }
+    };
+
+    if captured.reports.is_empty() {
+        eprint!("{}", captured.stderr);
+        return;
+    }
+
+    for report in &captured.reports {
+        let Some(part) = report.part() else {
+            continue;
+        };
+
+        let answer = report.answer.as_deref().unwrap_or("✖");
+        println!(
+            "Part {part}: {ANSI_BOLD}{answer}{ANSI_RESET} {ANSI_ITALIC}({:.1?}){ANSI_RESET}",
+            report.stats.median
+        );
+    }
+}
+
+fn print_stderr(output: &Output) {
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+}
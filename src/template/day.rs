@@ -2,13 +2,15 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent in a given year (i.e. an integer in range 1 to 25, or 1 to 12 since 2025).
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -19,38 +21,52 @@ const SERVER_UTC_OFFSET: i32 = -5;
 /// assert_eq!(day.to_string(), "08")
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Day(u8);
+pub struct Day {
+    year: Year,
+    day: u8,
+}
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
+    /// Creates a [`Day`] of the selected year from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        Self::with_year(Year::current(), day)
+    }
+
+    /// Creates a [`Day`] of the given year from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn with_year(year: Year, day: u8) -> Option<Self> {
+        if day == 0 || day > year.days() {
             return None;
         }
-        Some(Self(day))
+        Some(Self { year, day })
     }
 
     // Not part of the public API
     #[doc(hidden)]
-    pub const fn __new_unchecked(day: u8) -> Self {
-        Self(day)
+    pub const fn __new_unchecked(day: u8, year: Year) -> Self {
+        Self { year, day }
     }
 
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
-        self.0
+        self.day
+    }
+
+    pub fn year(self) -> Year {
+        self.year
     }
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of this year's advent, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        if today.month() == 12 {
+            let year = Year::new(u16::try_from(today.year()).ok()?)?;
+            Self::with_year(year, u8::try_from(today.day()).ok()?)
         } else {
             None
         }
@@ -59,19 +75,19 @@ impl Day {
 
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:02}", self.0)
+        write!(f, "{:02}", self.day)
     }
 }
 
 impl PartialEq<u8> for Day {
     fn eq(&self, other: &u8) -> bool {
-        self.0.eq(other)
+        self.day.eq(other)
     }
 }
 
 impl PartialOrd<u8> for Day {
     fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(other)
+        self.day.partial_cmp(other)
     }
 }
 
@@ -81,8 +97,15 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_year(Year::current(), s)
+    }
+}
+
+impl Day {
+    /// Parses a [`Day`] of the given year, e.g. a day that is stored without its year in a data file of that year.
+    pub fn from_str_with_year(year: Year, s: &str) -> Result<Self, DayFromStrError> {
         let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        Self::with_year(year, day).ok_or(DayFromStrError)
     }
 }
 
@@ -94,26 +117,31 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        f.write_str("expecting a day number between 1 and 25 (1 and 12 since 2025)")
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of the selected year.
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of a year, from the 1st to the last day of its calendar.
 pub struct AllDays {
+    year: Year,
     current: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::for_year(Year::current())
+    }
+
+    pub fn for_year(year: Year) -> Self {
+        Self { year, current: 1 }
     }
 }
 
@@ -121,11 +149,11 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.year.days() {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
-        let day = Day(self.current);
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the day count.
+        let day = Day::__new_unchecked(self.current, self.year);
         self.current += 1;
 
         Some(day)
@@ -135,18 +163,25 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// Without a year, the day belongs to the year `AOC_YEAR` is set to at compile time.
 #[macro_export]
 macro_rules! day {
-    ($day:expr) => {{
+    ($day:expr) => {
+        $crate::day!(
+            $day,
+            $crate::template::Year::__from_env(option_env!("AOC_YEAR"))
+        )
+    };
+    ($day:expr, $year:expr) => {{
         const _ASSERT: () = assert!(
-            $day != 0 && $day <= 25,
+            $day != 0 && $day <= $year.days(),
             concat!(
                 "invalid day number `",
                 $day,
-                "`, expecting a value between 1 and 25"
+                "`, expecting a value between 1 and the number of days of the year"
             ),
         );
-        $crate::template::Day::__new_unchecked($day)
+        $crate::template::Day::__new_unchecked($day, $year)
     }};
 }
 
@@ -154,39 +189,49 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, AllDays, Day};
+    use crate::template::Year;
 
     #[test]
     fn all_days_iterator() {
         let mut iter = all_days();
 
-        assert_eq!(iter.next(), Some(Day(1)));
-        assert_eq!(iter.next(), Some(Day(2)));
-        assert_eq!(iter.next(), Some(Day(3)));
-        assert_eq!(iter.next(), Some(Day(4)));
-        assert_eq!(iter.next(), Some(Day(5)));
-        assert_eq!(iter.next(), Some(Day(6)));
-        assert_eq!(iter.next(), Some(Day(7)));
-        assert_eq!(iter.next(), Some(Day(8)));
-        assert_eq!(iter.next(), Some(Day(9)));
-        assert_eq!(iter.next(), Some(Day(10)));
-        assert_eq!(iter.next(), Some(Day(11)));
-        assert_eq!(iter.next(), Some(Day(12)));
-        assert_eq!(iter.next(), Some(Day(13)));
-        assert_eq!(iter.next(), Some(Day(14)));
-        assert_eq!(iter.next(), Some(Day(15)));
-        assert_eq!(iter.next(), Some(Day(16)));
-        assert_eq!(iter.next(), Some(Day(17)));
-        assert_eq!(iter.next(), Some(Day(18)));
-        assert_eq!(iter.next(), Some(Day(19)));
-        assert_eq!(iter.next(), Some(Day(20)));
-        assert_eq!(iter.next(), Some(Day(21)));
-        assert_eq!(iter.next(), Some(Day(22)));
-        assert_eq!(iter.next(), Some(Day(23)));
-        assert_eq!(iter.next(), Some(Day(24)));
-        assert_eq!(iter.next(), Some(Day(25)));
+        assert_eq!(iter.next(), Some(Day::new(1).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(2).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(3).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(4).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(5).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(6).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(7).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(8).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(9).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(10).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(11).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(12).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(13).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(14).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(15).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(16).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(17).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(18).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(19).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(20).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(21).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(22).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(23).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(24).unwrap()));
+        assert_eq!(iter.next(), Some(Day::new(25).unwrap()));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn respects_calendar_length() {
+        let year = Year::new(2025).unwrap();
+        assert_eq!(AllDays::for_year(year).count(), 12);
+        assert!(Day::with_year(year, 12).is_some());
+        assert!(Day::with_year(year, 13).is_none());
+        assert!(Day::with_year(Year::new(2024).unwrap(), 25).is_some());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
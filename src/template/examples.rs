@@ -0,0 +1,140 @@
+/// Runs the examples listed in the manifests of all days and compares their answers.
+/// The generated integration test `tests/examples.rs` calls [`check_examples`] with the bins cargo built for it.
+use std::{
+    env, fs,
+    path::Path,
+    process::{self, Command},
+};
+
+use crate::template::{
+    manifest::{Example, Manifest},
+    report::{PartReport, REPORT_FILE_ENV},
+    Day, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Run every example with an expected answer against the bin of its day.
+/// `bins` holds the name of every bin (e.g. `2024-01`) and the path of its executable.
+///
+/// # Panics
+/// Panics if an example produces a different answer or can't be run, or if a manifest is malformed, after all examples ran.
+pub fn check_examples(bins: &[(&str, &str)]) {
+    let mut failures = vec![];
+    let mut passed = 0;
+
+    for &(name, executable) in bins {
+        let Some(day) = parse_bin_name(name) else {
+            failures.push(format!("{name}: not a valid bin name."));
+            continue;
+        };
+
+        let manifest = match Manifest::read_from_file(day) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                println!("✖ {name}");
+                failures.push(format!("{name}: invalid example manifest {e}"));
+                continue;
+            }
+        };
+
+        for (i, example) in manifest.examples.iter().enumerate() {
+            let Some(expected) = &example.answer else {
+                continue;
+            };
+
+            let label = format!("{name} part {} ({})", example.part, example.file);
+
+            match run_example(day, executable, example, i) {
+                Ok(actual) if actual.as_deref() == Some(expected) => {
+                    println!("✓ {label}");
+                    passed += 1;
+                }
+                Ok(actual) => {
+                    let actual = actual.unwrap_or_else(|| "-".into());
+                    println!("✖ {label}");
+                    failures.push(format!("{label}: expected `{expected}`, got `{actual}`."));
+                }
+                Err(e) => {
+                    println!("✖ {label}");
+                    failures.push(format!("{label}: {e}"));
+                }
+            }
+        }
+    }
+
+    println!(
+        "{ANSI_BOLD}Examples:{ANSI_RESET} {passed} passed, {} failed.",
+        failures.len()
+    );
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+/// Run a single example and return the answer of its part.
+fn run_example(
+    day: Day,
+    executable: &str,
+    example: &Example,
+    index: usize,
+) -> Result<Option<String>, String> {
+    let report_path = env::temp_dir().join(format!(
+        "aoc-example-{}-{}-{day}-{index}.jsonl",
+        process::id(),
+        day.year()
+    ));
+    let _ = fs::remove_file(&report_path);
+
+    let output = Command::new(executable)
+        .args(["--input", &example.get_path(day)])
+        .args(["--part", &example.part.to_string()])
+        .args(
+            example
+                .params
+                .iter()
+                .flat_map(|(name, value)| ["--param".to_string(), format!("{name}={value}")]),
+        )
+        .env(REPORT_FILE_ENV, &report_path)
+        .output()
+        .map_err(|e| format!("failed to run bin: {e}"))?;
+
+    let reports = read_reports(&report_path)?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "bin exited with {}: {}",
+            output.status,
+            stderr.trim()
+        ));
+    }
+
+    Ok(reports
+        .into_iter()
+        .find(|r| r.part() == Some(example.part))
+        .and_then(|r| r.answer))
+}
+
+fn read_reports(report_path: &Path) -> Result<Vec<PartReport>, String> {
+    let reports = PartReport::read_all(report_path);
+    let _ = fs::remove_file(report_path);
+    reports
+}
+
+/// Inverse of `get_bin_name`, e.g. `2024-01` is day 1 of 2024.
+fn parse_bin_name(name: &str) -> Option<Day> {
+    let (year, day) = name.split_once('-')?;
+    Day::with_year(year.parse::<Year>().ok()?, day.parse().ok()?)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_bin_name;
+    use crate::template::{Day, Year};
+
+    #[test]
+    fn parses_bin_names() {
+        let year = Year::new(2017).unwrap();
+        assert_eq!(parse_bin_name("2017-05"), Day::with_year(year, 5));
+        assert_eq!(parse_bin_name("2025-13"), None);
+        assert_eq!(parse_bin_name("all"), None);
+    }
+}
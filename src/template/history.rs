@@ -0,0 +1,220 @@
+/// History of benchmark runs, stored alongside the latest timings in `data/<year>/timings.json`.
+/// Every entry records the commit and build profile it was measured with, so runs can be compared over time.
+use std::{
+    collections::HashMap,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{stats::BenchStats, Day, Year};
+
+/// Context of a benchmark run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunInfo {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the checked out commit, if run in a git repository.
+    pub commit: Option<String>,
+    pub profile: String,
+}
+
+impl RunInfo {
+    /// Describe a run of the current working copy.
+    pub fn current(is_release: bool) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let commit = Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+        RunInfo {
+            timestamp,
+            commit,
+            profile: if is_release { "release" } else { "dev" }.into(),
+        }
+    }
+}
+
+/// One benchmark of a single part. Part `0` is the parse step of trait-based solutions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub day: Day,
+    pub part: u8,
+    pub run: RunInfo,
+    pub stats: BenchStats,
+}
+
+/// Change of the median duration of a part between two runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Delta {
+    pub day: Day,
+    pub part: u8,
+    pub previous: Duration,
+    pub current: Duration,
+}
+
+impl Delta {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn percent(&self) -> f64 {
+        let previous = self.previous.as_nanos() as f64;
+        let current = self.current.as_nanos() as f64;
+
+        if previous == 0.0 {
+            return 0.0;
+        }
+
+        (current - previous) / previous * 100.0
+    }
+
+    /// How many times faster the current run is than the previous one.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn speedup(&self) -> f64 {
+        self.previous.as_nanos() as f64 / self.current.as_nanos().max(1) as f64
+    }
+
+    /// A part regressed if it got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.run.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .run
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "profile".into(),
+            JsonValue::String(value.run.profile.clone()),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl HistoryEntry {
+    /// Parse a history entry of `year` from JSON.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub(crate) fn from_json(value: &JsonValue, year: Year) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str_with_year(year, day).ok())
+            .ok_or("Expected history.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .filter(|part| *part <= 2)
+            .ok_or("Expected history.part to be 0, 1 or 2.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|timestamp| *timestamp as u64)
+            .ok_or("Expected history.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected history.commit to be null or string.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected history.profile to be a string.")?;
+
+        let stats = BenchStats::try_from(
+            json.get("stats")
+                .ok_or("Expected history entry to have key `stats`.")?,
+        )?;
+
+        Ok(HistoryEntry {
+            day,
+            part,
+            run: RunInfo {
+                timestamp,
+                commit: commit.cloned(),
+                profile: profile.clone(),
+            },
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+    use tinyjson::JsonValue;
+
+    use super::{Delta, HistoryEntry, RunInfo};
+    use crate::{
+        day,
+        template::{stats::BenchStats, Year},
+    };
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = HistoryEntry {
+            day: day!(3),
+            part: 2,
+            run: RunInfo {
+                timestamp: 1_733_000_000,
+                commit: Some("abc1234".into()),
+                profile: "release".into(),
+            },
+            stats: BenchStats::from_samples(&[Duration::from_micros(120)]),
+        };
+
+        let json = JsonValue::from(&entry).stringify().unwrap();
+        let parsed =
+            HistoryEntry::from_json(&JsonValue::from_str(&json).unwrap(), Year::current()).unwrap();
+        assert_eq!(parsed, entry);
+    }
+
+    #[test]
+    fn computes_deltas() {
+        let delta = Delta {
+            day: day!(1),
+            part: 1,
+            previous: Duration::from_millis(10),
+            current: Duration::from_millis(12),
+        };
+
+        assert!((delta.percent() - 20.0).abs() < f64::EPSILON);
+        assert!(delta.is_regression(10.0));
+        assert!(!delta.is_regression(25.0));
+        assert!((delta.speedup() - 10.0 / 12.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,143 @@
+/// Checks solutions for tests that still contain the placeholder assertions of the scaffold template.
+/// Such tests pass while proving nothing, so they are flagged once a part has been solved.
+/// Also finds parts that still have the stub body of the template.
+use std::fs;
+
+use crate::template::{answers::Answers, run_multi::get_path_for_bin, Day};
+
+static PLACEHOLDER_ASSERT: &str = "assert_eq!(result, None);";
+static TEST_NAMES: [&str; 2] = ["test_part_one", "test_part_two"];
+static PART_NAMES: [&str; 2] = ["part_one", "part_two"];
+
+/// A solved part whose example test still asserts `None`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Warning {
+    pub day: Day,
+    pub part: u8,
+}
+
+/// Lint a set of days. A part counts as solved if its answer is stored in `answers`.
+pub fn lint(days: impl Iterator<Item = Day>, answers: &Answers) -> Vec<Warning> {
+    days.filter_map(|day| Some((day, fs::read_to_string(get_path_for_bin(day)).ok()?)))
+        .flat_map(|(day, source)| {
+            find_placeholder_asserts(&source)
+                .into_iter()
+                .filter(move |&part| answers.get(day, part).is_some())
+                .map(move |part| Warning { day, part })
+        })
+        .collect()
+}
+
+/// The parts whose test function asserts the placeholder `None`.
+fn find_placeholder_asserts(source: &str) -> Vec<u8> {
+    (1..=2)
+        .filter(|&part| {
+            let name = format!("fn {}(", TEST_NAMES[usize::from(part - 1)]);
+            source.find(&name).is_some_and(|start| {
+                let body = &source[start + name.len()..];
+                // the body of a test ends where the next function starts.
+                let body = body.find("fn ").map_or(body, |end| &body[..end]);
+                body.contains(PLACEHOLDER_ASSERT)
+            })
+        })
+        .collect()
+}
+
+/// The parts whose function still has the stub body of the template, i.e. only returns `None`.
+/// Comments in the body are ignored.
+pub fn find_stub_parts(source: &str) -> Vec<u8> {
+    (1..=2)
+        .filter(|&part| {
+            let name = format!("fn {}(", PART_NAMES[usize::from(part - 1)]);
+            source
+                .find(&name)
+                .and_then(|start| function_body(&source[start..]))
+                .is_some_and(|body| {
+                    body.lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+                        .eq(["None"])
+                })
+        })
+        .collect()
+}
+
+/// The body of the first function in `source`, without its braces.
+fn function_body(source: &str) -> Option<&str> {
+    let start = source.find('{')? + 1;
+    let mut depth = 1;
+
+    for (i, c) in source[start..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&source[start..start + i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_placeholder_asserts, find_stub_parts};
+
+    #[test]
+    fn finds_placeholder_asserts() {
+        let source = [
+            "fn test_part_one() {",
+            "    let result = part_one(&input);",
+            "    assert_eq!(result, Some(11));",
+            "}",
+            "fn test_part_two() {",
+            "    let result = part_two(&input);",
+            "    assert_eq!(result, None);",
+            "}",
+        ]
+        .join("\n");
+
+        assert_eq!(find_placeholder_asserts(&source), vec![2]);
+    }
+
+    #[test]
+    fn ignores_sources_without_tests() {
+        assert!(find_placeholder_asserts("fn part_one() { assert_eq!(result, None); }").is_empty());
+    }
+
+    #[test]
+    fn finds_stub_parts() {
+        let source = [
+            "pub fn part_one(_input: &str) -> Option<u64> {",
+            "    if _input.is_empty() { None } else { Some(1) }",
+            "}",
+            "pub fn part_two(_: &str) -> Option<u64> {",
+            "    // TODO",
+            "    None",
+            "}",
+        ]
+        .join("\n");
+
+        assert_eq!(find_stub_parts(&source), vec![2]);
+    }
+
+    #[test]
+    fn ignores_solved_parts() {
+        let source = [
+            "pub fn part_one(input: &str) -> Option<u64> {",
+            "    input.lines().count().try_into().ok()",
+            "}",
+            "pub fn part_two(input: &str) -> Option<u64> {",
+            "    let _ = input;",
+            "    None",
+            "}",
+        ]
+        .join("\n");
+
+        assert!(find_stub_parts(&source).is_empty());
+    }
+}
@@ -0,0 +1,334 @@
+/// Per-day manifest of example files and the answers they are expected to produce.
+/// Stored next to the examples of a day, e.g. `data/2024/examples/01.json`.
+/// A part can have several examples, each with its own file, answer and parameters.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{get_data_path, Day};
+
+/// An example file of a day and the answer it should produce for a part.
+/// `params` hold puzzle parameters that differ from the real input, e.g. a grid size or a step count.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// File name in the examples directory of the year, e.g. `01.txt` or `01-2.txt`.
+    pub file: String,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub params: BTreeMap<String, String>,
+}
+
+impl Example {
+    pub fn get_path(&self, day: Day) -> String {
+        get_data_path(day.year(), &format!("examples/{}", self.file))
+    }
+
+    /// The suffix of the example file of a day, i.e. `Some(None)` for `01.txt` and `Some(Some(2))` for `01-2.txt`.
+    /// Returns `None` for files that do not follow this naming scheme.
+    pub fn file_suffix(&self, day: Day) -> Option<Option<u8>> {
+        let name = self
+            .file
+            .strip_suffix(".txt")?
+            .strip_prefix(&day.to_string())?;
+
+        match name.strip_prefix('-') {
+            None if name.is_empty() => Some(None),
+            None => None,
+            Some(suffix) => suffix.parse().ok().map(Some),
+        }
+    }
+}
+
+/// All examples of a single day.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    pub fn get_path(day: Day) -> String {
+        get_data_path(day.year(), &format!("examples/{day}.json"))
+    }
+
+    /// Dehydrate the manifest of a day to a JSON file.
+    pub fn store_file(&self, day: Day) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(Self::get_path(day))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the manifest of a day from a JSON file. If not present, returns an empty manifest.
+    /// Fails if the file can't be read or is not a valid manifest, so broken manifests don't pass as having no examples.
+    pub fn read_from_file(day: Day) -> Result<Self, String> {
+        Self::read_from_path(&Self::get_path(day))
+    }
+
+    fn read_from_path(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Manifest::try_from(content).map_err(|e| format!("\"{path}\": {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Manifest::default()),
+            Err(e) => Err(format!("\"{path}\": {e}")),
+        }
+    }
+
+    /// The first example of a part that has an expected answer.
+    pub fn get_answered(&self, part: u8) -> Option<&Example> {
+        self.examples
+            .iter()
+            .find(|e| e.part == part && e.answer.is_some())
+    }
+
+    /// Set the expected answer of a part for an example file, adding the example if needed.
+    pub fn set_answer(&mut self, file: &str, part: u8, answer: String) {
+        match self
+            .examples
+            .iter_mut()
+            .find(|e| e.file == file && e.part == part)
+        {
+            Some(example) => example.answer = Some(answer),
+            None => self.examples.push(Example {
+                file: file.into(),
+                part,
+                answer: Some(answer),
+                params: BTreeMap::new(),
+            }),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Manifest> for JsonValue {
+    fn from(value: &Manifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "examples".into(),
+            JsonValue::Array(value.examples.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Manifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_examples = json
+            .get("examples")
+            .ok_or("expected JSON document to have key `examples`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.examples` to be an array.")?;
+
+        Ok(Manifest {
+            examples: json_examples
+                .iter()
+                .map(Example::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Example> for JsonValue {
+    fn from(value: &Example) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("file".into(), JsonValue::String(value.file.clone()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        if !value.params.is_empty() {
+            let params = value
+                .params
+                .iter()
+                .map(|(key, value)| {
+                    // keep numbers as numbers, so hand-written manifests stay as they are.
+                    #[allow(clippy::cast_precision_loss)]
+                    let value = value.parse::<i64>().map_or_else(
+                        |_| JsonValue::String(value.clone()),
+                        |n| JsonValue::Number(n as f64),
+                    );
+                    (key.clone(), value)
+                })
+                .collect();
+            map.insert("params".into(), JsonValue::Object(params));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Example {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example to be a JSON object.")?;
+
+        let file = json
+            .get("file")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected example.file to be a string.")?
+            .clone();
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .filter(|part| (1..=2).contains(part))
+            .ok_or("Expected example.part to be 1 or 2.")?;
+
+        let answer = match json.get("answer") {
+            None | Some(JsonValue::Null) => None,
+            Some(answer) => Some(
+                answer
+                    .get::<String>()
+                    .ok_or("Expected example.answer to be a string or null.")?
+                    .clone(),
+            ),
+        };
+
+        let mut params = BTreeMap::new();
+        if let Some(json_params) = json.get("params") {
+            let json_params = json_params
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected example.params to be a JSON object.")?;
+
+            for (key, value) in json_params {
+                let value = match value {
+                    JsonValue::String(s) => s.clone(),
+                    JsonValue::Number(n) => n.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "Expected example.params.{key} to be a string or number."
+                        ))
+                    }
+                };
+                params.insert(key.clone(), value);
+            }
+        }
+
+        Ok(Example {
+            file,
+            part,
+            answer,
+            params,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::BTreeMap, env, fs, process};
+    use tinyjson::JsonValue;
+
+    use super::{Example, Manifest};
+    use crate::day;
+
+    #[test]
+    fn roundtrips_json() {
+        let mut manifest = Manifest::default();
+        manifest.set_answer("01.txt", 1, "11".into());
+        manifest.set_answer("01-2.txt", 2, "31".into());
+
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert_eq!(Manifest::try_from(json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn parses_multiple_examples_with_params() {
+        let json = r#"{ "examples": [
+            { "file": "18.txt", "part": 1, "answer": "22", "params": { "size": 7, "steps": "12" } },
+            { "file": "18-2.txt", "part": 1, "answer": "5" }
+        ] }"#;
+
+        let manifest = Manifest::try_from(json.to_string()).unwrap();
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].params["size"], "7");
+        assert_eq!(manifest.examples[0].params["steps"], "12");
+        assert!(manifest.examples[1].params.is_empty());
+
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert_eq!(Manifest::try_from(json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn replaces_existing_answers() {
+        let mut manifest = Manifest::default();
+        manifest.set_answer("01.txt", 1, "10".into());
+        manifest.set_answer("01.txt", 1, "11".into());
+
+        assert_eq!(
+            manifest.examples,
+            vec![Example {
+                file: "01.txt".into(),
+                part: 1,
+                answer: Some("11".into()),
+                params: BTreeMap::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_file_suffix() {
+        let example = |file: &str| Example {
+            file: file.into(),
+            part: 1,
+            answer: None,
+            params: BTreeMap::new(),
+        };
+
+        assert_eq!(example("01.txt").file_suffix(day!(1)), Some(None));
+        assert_eq!(example("01-2.txt").file_suffix(day!(1)), Some(Some(2)));
+        assert_eq!(example("01-x.txt").file_suffix(day!(1)), None);
+        assert_eq!(example("02.txt").file_suffix(day!(1)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_part() {
+        let json = r#"{ "examples": [{ "file": "01.txt", "part": 3, "answer": "1" }] }"#;
+        Manifest::try_from(json.to_string()).unwrap();
+    }
+
+    #[test]
+    fn reads_missing_manifests_as_empty() {
+        let path = env::temp_dir().join(format!("aoc-manifest-{}-missing.json", process::id()));
+        let manifest = Manifest::read_from_path(&path.to_string_lossy()).unwrap();
+        assert!(manifest.examples.is_empty());
+    }
+
+    #[test]
+    fn fails_for_malformed_manifests() {
+        let path = env::temp_dir().join(format!("aoc-manifest-{}-malformed.json", process::id()));
+        fs::write(&path, r#"{ "examples": [{ "file": "01.txt" "#).unwrap();
+
+        let result = Manifest::read_from_path(&path.to_string_lossy());
+        let _ = fs::remove_file(&path);
+        assert!(result.is_err());
+    }
+}
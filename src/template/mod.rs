@@ -1,25 +1,61 @@
-use std::{env, fs};
+use std::{
+    env, fs,
+    io::{self, Read},
+};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod params;
 pub mod runner;
+pub mod submission;
 
 pub use day::*;
+pub use solution::Solution;
+pub use year::*;
 
+mod answers;
+mod baseline;
 mod day;
+mod history;
+mod lint;
+mod manifest;
+#[cfg(feature = "test_lib")]
+mod mock_server;
+mod progress;
+/// Extracts examples and their expected answers from a puzzle description, as written by `cargo download`.
+/// Puzzle pages present examples as code blocks and emphasize their answers, e.g. "`*11*`".
+mod puzzle;
 mod readme_benchmarks;
+mod readme_stars;
+mod registry;
+mod report;
 mod run_multi;
+mod solution;
+mod stats;
 mod timings;
+mod watcher;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Returns the path of a file in the data directory of a year. E.g. like `data/2024/timings.json`.
+#[must_use]
+pub fn get_data_path(year: Year, path: &str) -> String {
+    format!("data/{year}/{path}")
+}
+
+/// Helper function that reads a text file of the day's year to a string. E.g. like `data/2024/inputs/01.txt`.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(day.year().to_string())
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
@@ -30,15 +66,62 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(day.year().to_string())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Reads the input a solution is run against.
+/// Defaults to the day's puzzle input. `--input <path>` reads another file instead, `--input -` reads from stdin.
+/// `--example` reads the day's example, `--example <n>` its numbered variant (e.g. `01-2.txt`).
+/// Examples are run with the puzzle parameters listed for them in the example manifest.
+#[must_use]
+pub fn read_input(day: Day) -> String {
+    let args: Vec<String> = env::args().collect();
+    let value_of = |name: &str| {
+        args.iter()
+            .position(|x| x == name)
+            .map(|i| args.get(i + 1).map(String::as_str))
+    };
+
+    if let Some(suffix) = value_of("--example") {
+        let selected_part = value_of("--part").flatten().and_then(|p| p.parse().ok());
+
+        return match suffix.and_then(|suffix| suffix.parse().ok()) {
+            Some(suffix) => {
+                params::use_example_params(day, &format!("{day}-{suffix}.txt"), selected_part);
+                read_file_part("examples", day, suffix)
+            }
+            None => {
+                params::use_example_params(day, &format!("{day}.txt"), selected_part);
+                read_file("examples", day)
+            }
+        };
+    }
+
+    let path = value_of("--input").flatten();
+
+    match path {
+        None => read_file("inputs", day),
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .expect("could not read input from stdin");
+            input
+        }
+        Some(path) => fs::read_to_string(path).expect("could not open input file"),
+    }
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// The year is taken from the solution's directory (e.g. `src/bin/2024/01.rs`).
+/// The parts are run by a generated `run` function, which is shared by `main` and the in-process runner.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// If the second parameter is the name of a type implementing [`Solution`], the input is parsed once and shared by both parts.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -50,19 +133,36 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, $solution:ident) => {
+        $crate::solution!(@main $day, |input| {
+            $crate::template::runner::run_solution::<$solution>(input, DAY);
+        });
+    };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@main $day, |input| {
+            use $crate::template::runner::*;
+            $( run_part($func, input, DAY, $part); )*
+        });
+    };
+
+    (@main $day:expr, |$input:ident| $run:block) => {
+        /// The year of the current day.
+        pub const YEAR: $crate::template::Year =
+            $crate::template::Year::__from_path(file!(), option_env!("AOC_YEAR"));
+
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        pub const DAY: $crate::template::Day = $crate::day!($day, YEAR);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        /// Run the solution against an input.
+        pub fn run($input: &str) $run
+
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            run(&$crate::template::read_input(DAY));
         }
     };
 }
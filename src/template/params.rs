@@ -0,0 +1,158 @@
+/// Named puzzle parameters, for constants that differ between the example and the real input, e.g. a grid size.
+/// A solution declares a parameter with its real-input default, e.g. `param("size", 71)`.
+///
+/// The default is overridden by, in order of precedence:
+///  1. parameters set for the current thread, e.g. by a test via [`read_example`].
+///  2. `--param <name>=<value>` arguments, e.g. `cargo solve 18 --param size=7`.
+///  3. the parameters of the example in the example manifest, when running with `--example`.
+use std::{cell::RefCell, collections::BTreeMap, env, fs, str::FromStr, sync::OnceLock};
+
+use crate::template::{manifest::Manifest, read_file, read_file_part, Day};
+
+type Params = BTreeMap<String, String>;
+
+thread_local! {
+    static THREAD_PARAMS: RefCell<Params> = const { RefCell::new(BTreeMap::new()) };
+}
+
+static ARG_PARAMS: OnceLock<Params> = OnceLock::new();
+static EXAMPLE_PARAMS: OnceLock<Params> = OnceLock::new();
+
+/// The value of a puzzle parameter, or `default` if it is not overridden.
+///
+/// # Panics
+/// Panics if the value of an override can't be parsed as `T`.
+pub fn param<T: FromStr>(name: &str, default: T) -> T {
+    let value = THREAD_PARAMS
+        .with_borrow(|params| params.get(name).cloned())
+        .or_else(|| ARG_PARAMS.get_or_init(parse_args).get(name).cloned())
+        .or_else(|| EXAMPLE_PARAMS.get().and_then(|p| p.get(name).cloned()));
+
+    match value {
+        None => default,
+        Some(value) => value.parse().unwrap_or_else(|_| {
+            panic!(
+                "Expected parameter `{name}` to be a {}, got `{value}`.",
+                std::any::type_name::<T>()
+            )
+        }),
+    }
+}
+
+/// Override parameters for the current thread. Tests run on their own thread, so overrides don't leak between tests.
+pub fn set_params<'a>(params: impl IntoIterator<Item = (&'a str, &'a str)>) {
+    THREAD_PARAMS.with_borrow_mut(|current| {
+        current.extend(params.into_iter().map(|(k, v)| (k.into(), v.into())));
+    });
+}
+
+/// Read the example of a part that is listed in the example manifest and use its parameters for the current thread.
+/// Falls back to the default example file (`DD.txt`) if the manifest has no example for the part.
+#[must_use]
+pub fn read_example(day: Day, part: u8) -> String {
+    let manifest = Manifest::read_from_file(day)
+        .unwrap_or_else(|e| panic!("could not read example manifest {e}"));
+
+    let Some(example) = manifest
+        .get_answered(part)
+        .or_else(|| manifest.examples.iter().find(|e| e.part == part))
+    else {
+        return read_file("examples", day);
+    };
+
+    set_params(example.params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+
+    match example.file_suffix(day) {
+        Some(Some(suffix)) => read_file_part("examples", day, suffix),
+        Some(None) => read_file("examples", day),
+        None => fs::read_to_string(example.get_path(day)).expect("could not open example file"),
+    }
+}
+
+/// Use the parameters of an example file for the rest of the run, as listed in the example manifest.
+/// If the file is listed for both parts, the entry of the part selected with `--part` is preferred.
+pub(crate) fn use_example_params(day: Day, file: &str, part: Option<u8>) {
+    let manifest = Manifest::read_from_file(day)
+        .unwrap_or_else(|e| panic!("could not read example manifest {e}"));
+    let mut examples = manifest.examples.iter().filter(|e| e.file == file);
+
+    let example = match part {
+        Some(part) => examples
+            .clone()
+            .find(|e| e.part == part)
+            .or(examples.next()),
+        None => examples.next(),
+    };
+
+    if let Some(example) = example {
+        let _ = EXAMPLE_PARAMS.set(example.params.clone());
+    }
+}
+
+fn parse_args() -> Params {
+    parse_param_args(env::args())
+}
+
+/// Collect `--param <name>=<value>` pairs from a list of arguments.
+fn parse_param_args(args: impl IntoIterator<Item = String>) -> Params {
+    let args: Vec<String> = args.into_iter().collect();
+
+    args.windows(2)
+        .filter(|pair| pair[0] == "--param")
+        .filter_map(|pair| pair[1].split_once('='))
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Validate a `--param` argument of the CLI, e.g. `size=7`.
+pub fn parse_param_arg(value: &str) -> Result<String, &'static str> {
+    match value.split_once('=') {
+        Some((name, _)) if !name.is_empty() => Ok(value.to_string()),
+        _ => Err("expecting a parameter as <name>=<value>"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{param, parse_param_arg, parse_param_args, set_params};
+
+    #[test]
+    fn uses_default_without_overrides() {
+        assert_eq!(param("size", 71), 71);
+    }
+
+    #[test]
+    fn overrides_for_current_thread() {
+        set_params([("size", "7")]);
+        assert_eq!(param("size", 71), 7);
+        assert_eq!(param("steps", 1024), 1024);
+
+        let other_thread = std::thread::spawn(|| param("size", 71)).join().unwrap();
+        assert_eq!(other_thread, 71);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected parameter `size` to be a u32")]
+    fn panics_for_invalid_override() {
+        set_params([("size", "seven")]);
+        let _: u32 = param("size", 71);
+    }
+
+    #[test]
+    fn parses_param_args() {
+        let args = [
+            "01", "--param", "size=7", "--part", "1", "--param", "steps=12",
+        ];
+        let params = parse_param_args(args.map(String::from));
+        assert_eq!(params.len(), 2);
+        assert_eq!(params["size"], "7");
+        assert_eq!(params["steps"], "12");
+    }
+
+    #[test]
+    fn validates_param_arg() {
+        assert_eq!(parse_param_arg("size=7"), Ok("size=7".to_string()));
+        assert!(parse_param_arg("size").is_err());
+        assert!(parse_param_arg("=7").is_err());
+    }
+}
@@ -0,0 +1,283 @@
+/// Stars earned per day, synced from the AoC calendar or derived from local answers and submissions.
+use std::{collections::HashMap, fs, io, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    answers::Answers,
+    get_data_path,
+    submission::{SubmissionHistory, Verdict},
+    Day, Year,
+};
+
+static PROGRESS_FILE_PATH: &str = "progress.json";
+
+/// Represents the stars earned on a single day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayStars {
+    pub day: Day,
+    pub stars: u8,
+}
+
+/// Represents the stars earned on a set of days.
+/// The synced progress of the AoC profile page is stored, local progress is derived from answers and submissions.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub data: Vec<DayStars>,
+}
+
+impl Progress {
+    /// Dehydrate progress to the JSON file of `year`.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_data_path(year, PROGRESS_FILE_PATH))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate progress from the JSON file of `year`. If not present, returns empty progress.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_data_path(year, PROGRESS_FILE_PATH))
+            .map_err(|x| x.to_string())
+            .and_then(|json| Progress::from_json(&json, year))
+            .unwrap_or_default()
+    }
+
+    /// Derive the progress of a set of days from verified answers and accepted submissions.
+    /// Part two can only be solved after part one, so a solved part two earns both stars.
+    /// `AlreadySolved` verdicts do not count, as AoC also returns them for premature or duplicate submissions of part two.
+    pub fn from_local(
+        days: impl Iterator<Item = Day>,
+        answers: &Answers,
+        submissions: &SubmissionHistory,
+    ) -> Self {
+        let is_solved = |day: Day, part: u8| {
+            answers.get(day, part).is_some()
+                || submissions
+                    .data
+                    .iter()
+                    .any(|s| s.day == day && s.part == part && s.verdict == Verdict::Correct)
+        };
+
+        let data = days
+            .filter_map(|day| {
+                let stars = match (is_solved(day, 1), is_solved(day, 2)) {
+                    (_, true) => 2,
+                    (true, false) => 1,
+                    (false, false) => return None,
+                };
+                Some(DayStars { day, stars })
+            })
+            .collect();
+
+        Self { data }
+    }
+
+    /// Merge two sets of progress, keeping the most stars of every day.
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut data = self.data.clone();
+
+        for entry in &other.data {
+            match data.iter_mut().find(|d| d.day == entry.day) {
+                Some(existing) => existing.stars = existing.stars.max(entry.stars),
+                None => data.push(*entry),
+            }
+        }
+
+        data.sort_by_key(|d| d.day);
+        Self { data }
+    }
+
+    pub fn total_stars(&self) -> u32 {
+        self.data.iter().map(|d| u32::from(d.stars)).sum()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Progress> for JsonValue {
+    fn from(value: Progress) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl Progress {
+    /// Parse the progress of `year` from a JSON document. Days are stored without their year.
+    pub fn from_json(value: &str, year: Year) -> Result<Self, String> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Progress {
+            data: json_data
+                .iter()
+                .map(|v| DayStars::from_json(v, year))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayStars> for JsonValue {
+    fn from(value: &DayStars) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("stars".into(), JsonValue::Number(value.stars.into()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl DayStars {
+    /// Parse the stars of a day of `year` from JSON.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub(crate) fn from_json(value: &JsonValue, year: Year) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected progress entry to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str_with_year(year, day).ok())
+            .ok_or("Expected progress.day to be a Day struct.")?;
+
+        let stars = json
+            .get("stars")
+            .and_then(|v| v.get::<f64>())
+            .map(|stars| *stars as u8)
+            .filter(|stars| *stars <= 2)
+            .ok_or("Expected progress.stars to be 0, 1 or 2.")?;
+
+        Ok(DayStars { day, stars })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{DayStars, Progress};
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            submission::{SubmissionHistory, Verdict},
+            AllDays, Day, Year,
+        },
+    };
+
+    const YEAR: Year = match Year::new(2024) {
+        Some(year) => year,
+        None => unreachable!(),
+    };
+
+    fn get_mock_progress() -> Progress {
+        Progress {
+            data: vec![
+                DayStars {
+                    day: day!(1, YEAR),
+                    stars: 2,
+                },
+                DayStars {
+                    day: day!(2, YEAR),
+                    stars: 1,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn derives_progress_from_answers_and_submissions() {
+        let mut answers = Answers::default();
+        answers.set(day!(1, YEAR), 1, "11");
+        answers.set(day!(1, YEAR), 2, "31");
+
+        let mut submissions = SubmissionHistory::default();
+        submissions.record(
+            day!(2, YEAR),
+            1,
+            "1",
+            Verdict::Incorrect {
+                hint: None,
+                wait: None,
+            },
+        );
+        submissions.record(day!(3, YEAR), 2, "48", Verdict::Correct);
+        // AoC also answers a premature or duplicate submission of part two with `AlreadySolved`.
+        submissions.record(day!(4, YEAR), 2, "7", Verdict::AlreadySolved);
+
+        let progress =
+            Progress::from_local(AllDays::for_year(YEAR).take(4), &answers, &submissions);
+
+        assert_eq!(
+            progress.data,
+            vec![
+                DayStars {
+                    day: day!(1, YEAR),
+                    stars: 2
+                },
+                DayStars {
+                    day: day!(3, YEAR),
+                    stars: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merges_progress() {
+        let synced = Progress {
+            data: vec![
+                DayStars {
+                    day: day!(2, YEAR),
+                    stars: 2,
+                },
+                DayStars {
+                    day: day!(5, YEAR),
+                    stars: 1,
+                },
+            ],
+        };
+
+        let merged = get_mock_progress().merge(&synced);
+        assert_eq!(merged.data.len(), 3);
+        assert_eq!(merged.data[1].stars, 2);
+        assert_eq!(merged.total_stars(), 5);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let json = JsonValue::from(get_mock_progress()).stringify().unwrap();
+        assert_eq!(
+            Progress::from_json(&json, YEAR).unwrap(),
+            get_mock_progress()
+        );
+    }
+
+    #[test]
+    fn parses_days_of_given_year() {
+        let year = Year::new(2015).unwrap();
+        let json = r#"{ "data": [{ "day": "25", "stars": 2 }] }"#;
+        let progress = Progress::from_json(json, year).unwrap();
+        assert_eq!(progress.data[0].day, Day::with_year(year, 25).unwrap());
+    }
+}
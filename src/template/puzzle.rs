@@ -0,0 +1,169 @@
+/// A code block of the puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub content: String,
+    /// The part whose description contains the block.
+    pub part: u8,
+    /// Whether the paragraph before the block introduces an example, e.g. "For example:".
+    pub is_example: bool,
+}
+
+impl CodeBlock {
+    /// First line of the block, for listing blocks compactly.
+    pub fn preview(&self) -> &str {
+        self.content.lines().next().unwrap_or_default()
+    }
+}
+
+/// Collect all code blocks of a puzzle description in order.
+pub fn parse_code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut part = 1;
+    let mut previous_line = "";
+    let mut current: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        match (&mut current, line.trim_end() == "```") {
+            (None, true) => current = Some(vec![]),
+            (None, false) => {
+                if is_part_two_heading(line) {
+                    part = 2;
+                }
+                if !line.trim().is_empty() {
+                    previous_line = line;
+                }
+            }
+            (Some(lines), false) => lines.push(line),
+            (Some(lines), true) => {
+                blocks.push(CodeBlock {
+                    content: lines.join("\n") + "\n",
+                    part,
+                    is_example: previous_line.to_lowercase().contains("example"),
+                });
+                current = None;
+                previous_line = "";
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Guess the index of the example block of a part: the first block introduced as an example,
+/// otherwise the first block with more than one line, otherwise the first block.
+pub fn guess_example(blocks: &[CodeBlock], part: u8) -> Option<usize> {
+    let in_part = |i: &usize| blocks[*i].part == part;
+    let indices = || (0..blocks.len()).filter(in_part);
+
+    indices()
+        .find(|&i| blocks[i].is_example)
+        .or_else(|| indices().find(|&i| blocks[i].content.lines().count() > 1))
+        .or_else(|| indices().next())
+}
+
+/// The expected example answer of each part, i.e. the last emphasized code in its description.
+pub fn parse_answers(markdown: &str) -> [Option<String>; 2] {
+    let (part_one, part_two) = match markdown.lines().position(is_part_two_heading) {
+        Some(index) => {
+            let lines: Vec<&str> = markdown.lines().collect();
+            (lines[..index].join("\n"), Some(lines[index..].join("\n")))
+        }
+        None => (markdown.to_string(), None),
+    };
+
+    [
+        last_emphasized_code(&part_one),
+        part_two.as_deref().and_then(last_emphasized_code),
+    ]
+}
+
+/// Whether the description includes part two, which is only shown once part one is solved.
+pub fn has_part_two(markdown: &str) -> bool {
+    markdown.lines().any(is_part_two_heading)
+}
+
+fn is_part_two_heading(line: &str) -> bool {
+    line.starts_with("## --- Part Two")
+}
+
+fn last_emphasized_code(markdown: &str) -> Option<String> {
+    let mut last = None;
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find("`*") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("*`") else {
+            break;
+        };
+        last = Some(rest[..end].to_string());
+        rest = &rest[end + 2..];
+    }
+
+    last
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{guess_example, has_part_two, parse_answers, parse_code_blocks};
+
+    static PUZZLE: &str = "## --- Day 1: Historian Hysteria ---
+
+The lists are written in `*two columns*`:
+
+```
+a
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+The total distance is `*11*`.
+
+## --- Part Two ---
+
+This time, the similarity score is `*31*`.";
+
+    #[test]
+    fn parses_code_blocks() {
+        let blocks = parse_code_blocks(PUZZLE);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].content, "a\n");
+        assert!(!blocks[0].is_example);
+        assert_eq!(blocks[1].content, "3   4\n4   3\n");
+        assert!(blocks[1].is_example);
+        assert_eq!(blocks[1].part, 1);
+    }
+
+    #[test]
+    fn guesses_example_block() {
+        let blocks = parse_code_blocks(PUZZLE);
+        assert_eq!(guess_example(&blocks, 1), Some(1));
+        assert_eq!(guess_example(&blocks, 2), None);
+    }
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers(PUZZLE),
+            [Some("11".to_string()), Some("31".to_string())]
+        );
+    }
+
+    #[test]
+    fn parses_answers_without_part_two() {
+        let puzzle = PUZZLE.split("## --- Part Two").next().unwrap();
+        assert_eq!(parse_answers(puzzle), [Some("11".to_string()), None]);
+    }
+
+    #[test]
+    fn detects_part_two() {
+        assert!(has_part_two(PUZZLE));
+        assert!(!has_part_two(
+            PUZZLE.split("## --- Part Two").next().unwrap()
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+/// Module that updates the readme with the stars earned per day.
+/// The table has the same format as the one written by the `advent-readme-stars` action.
+use std::fs;
+
+use crate::template::{
+    progress::Progress,
+    readme_benchmarks::{update_table, Error},
+    Year,
+};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+fn construct_table(prefix: &str, year: Year, progress: &Progress) -> String {
+    let header = format!("{prefix} {year} Results");
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for entry in progress.data.iter().filter(|d| d.stars > 0) {
+        let star = |part: u8| if entry.stars >= part { "⭐" } else { " " };
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{year}/day/{}) | {} | {} |",
+            entry.day.into_inner(),
+            entry.day.into_inner(),
+            star(1),
+            star(2)
+        ));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, progress: &Progress) -> Result<(), Error> {
+    let table = construct_table("##", year, progress);
+    update_table(s, MARKER, &table)
+}
+
+pub fn update(year: Year, progress: &Progress) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, progress)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, MARKER};
+    use crate::{
+        day,
+        template::{
+            progress::{DayStars, Progress},
+            Year,
+        },
+    };
+
+    const YEAR: Year = match Year::new(2024) {
+        Some(year) => year,
+        None => unreachable!(),
+    };
+
+    fn get_mock_progress() -> Progress {
+        Progress {
+            data: vec![
+                DayStars {
+                    day: day!(1, YEAR),
+                    stars: 2,
+                },
+                DayStars {
+                    day: day!(2, YEAR),
+                    stars: 1,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\nbaz", MARKER);
+        update_content(&mut s, YEAR, &get_mock_progress()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn updates_existing_stars() {
+        let mut s = format!("foo\n{}\nbaz", MARKER);
+        update_content(&mut s, YEAR, &Progress::default()).unwrap();
+        update_content(&mut s, YEAR, &get_mock_progress()).unwrap();
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches("## 2024 Results").count(), 1);
+        assert!(s.contains("[Day 2]"));
+    }
+}
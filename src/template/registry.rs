@@ -0,0 +1,152 @@
+/// Generates the registry of the in-process runner (`src/bin/all`) and the examples test (`tests/examples.rs`).
+/// The registry includes every scaffolded day of every year as a module, so that all days can be run from a single binary.
+/// The examples test lists the bins of all days, so their examples can be checked against the example manifests.
+use std::{fs, io, path::Path};
+
+use crate::template::{
+    run_multi::{get_bin_name, get_path_for_bin},
+    AllDays, Day, Year,
+};
+
+static REGISTRY_FILE_PATH: &str = "./src/bin/all/registry.rs";
+static EXAMPLES_TEST_FILE_PATH: &str = "./tests/examples.rs";
+static BIN_DIR_PATH: &str = "./src/bin";
+static HEADER: &str = "// This file is generated by `cargo scaffold` and `cargo all --in-process`.";
+
+/// Regenerate the registry and the examples test from the solution files on disk.
+pub fn update() -> Result<(), io::Error> {
+    let days: Vec<Day> = get_years()?
+        .into_iter()
+        .flat_map(AllDays::for_year)
+        .filter(|day| Path::new(&get_path_for_bin(*day)).exists())
+        .collect();
+
+    write_if_changed(REGISTRY_FILE_PATH, &render(&days))?;
+    write_if_changed(EXAMPLES_TEST_FILE_PATH, &render_examples_test(&days))
+}
+
+/// Files are only written if their content changed, to avoid needless rebuilds.
+fn write_if_changed(path: &str, content: &str) -> Result<(), io::Error> {
+    if fs::read_to_string(path).is_ok_and(|current| current == content) {
+        return Ok(());
+    }
+
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, content)
+}
+
+/// The years that have a directory of solutions, e.g. `src/bin/2024`.
+fn get_years() -> Result<Vec<Year>, io::Error> {
+    let mut years = vec![];
+
+    for entry in fs::read_dir(BIN_DIR_PATH)? {
+        let entry = entry?;
+        if let Some(year) = entry.file_name().to_str().and_then(|s| s.parse().ok()) {
+            if entry.file_type()?.is_dir() {
+                years.push(year);
+            }
+        }
+    }
+
+    years.sort_unstable();
+    Ok(years)
+}
+
+fn get_module_name(day: Day) -> String {
+    format!("day_{}_{day}", day.year())
+}
+
+fn render(days: &[Day]) -> String {
+    let mut lines: Vec<String> = vec![
+        HEADER.into(),
+        "use advent_of_code::template::{runner::RunDay, Day};".into(),
+        String::new(),
+    ];
+
+    for day in days {
+        lines.push(format!("#[path = \"../{}/{day}.rs\"]", day.year()));
+        lines.push("#[allow(dead_code)]".into());
+        lines.push(format!("mod {};", get_module_name(*day)));
+    }
+
+    if !days.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines.push("/// All scaffolded days and the functions that run them.".into());
+
+    if days.is_empty() {
+        lines.push("pub static DAYS: &[(Day, RunDay)] = &[];".into());
+    } else {
+        lines.push("pub static DAYS: &[(Day, RunDay)] = &[".into());
+        for day in days {
+            let module = get_module_name(*day);
+            lines.push(format!("    ({module}::DAY, {module}::run),"));
+        }
+        lines.push("];".into());
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// The bins of all days are built by cargo for integration tests, their paths are passed as `CARGO_BIN_EXE_<name>`.
+fn render_examples_test(days: &[Day]) -> String {
+    let mut lines: Vec<String> = vec![
+        HEADER.into(),
+        String::new(),
+        "/// Runs the examples of every day against the answers in its example manifest, e.g. `data/2024/examples/01.json`.".into(),
+        "#[test]".into(),
+        "fn examples() {".into(),
+    ];
+
+    if days.is_empty() {
+        lines.push("    advent_of_code::template::examples::check_examples(&[]);".into());
+    } else {
+        lines.push("    advent_of_code::template::examples::check_examples(&[".into());
+        for day in days {
+            let name = get_bin_name(*day);
+            lines.push(format!(
+                "        (\"{name}\", env!(\"CARGO_BIN_EXE_{name}\")),"
+            ));
+        }
+        lines.push("    ]);".into());
+    }
+
+    lines.push("}".into());
+    lines.join("\n") + "\n"
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, render_examples_test};
+    use crate::template::{Day, Year};
+
+    #[test]
+    fn renders_registry() {
+        let day = |year, day| Day::with_year(Year::new(year).unwrap(), day).unwrap();
+        let registry = render(&[day(2024, 1), day(2025, 12)]);
+        assert!(registry
+            .contains("#[path = \"../2024/01.rs\"]\n#[allow(dead_code)]\nmod day_2024_01;\n"));
+        assert!(registry.contains("#[path = \"../2025/12.rs\"]"));
+        assert!(registry.contains(
+            "    (day_2024_01::DAY, day_2024_01::run),\n    (day_2025_12::DAY, day_2025_12::run),\n];"
+        ));
+    }
+
+    #[test]
+    fn renders_examples_test() {
+        let day = Day::with_year(Year::new(2024).unwrap(), 1).unwrap();
+        let test = render_examples_test(&[day]);
+        assert!(test.contains("        (\"2024-01\", env!(\"CARGO_BIN_EXE_2024-01\")),\n"));
+    }
+
+    #[test]
+    fn renders_empty_registry() {
+        let registry = render(&[]);
+        assert!(!registry.contains("mod day_"));
+        assert!(registry.contains("= &[];"));
+    }
+}
@@ -0,0 +1,425 @@
+/// Structured records that solution bins emit for every part they run, and for the parse step of trait-based solutions.
+/// The in-process runner also records the days that panicked.
+/// `run_multi` sets `AOC_REPORT_FILE` on its child processes and reads the records back
+/// as JSON lines, instead of parsing the human-readable output.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{stats::BenchStats, Day, Year};
+
+pub static REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+}
+
+/// The step of a solution that a report belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// The parse step of a trait-based [`Solution`](crate::template::Solution).
+    Parse,
+    /// Part `1` or `2`.
+    Part(u8),
+}
+
+/// Result of running one step of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartReport {
+    pub day: Day,
+    pub step: Step,
+    pub answer: Option<String>,
+    pub stats: BenchStats,
+    pub status: Status,
+}
+
+impl PartReport {
+    /// The part of the report, or `None` for the parse step.
+    pub fn part(&self) -> Option<u8> {
+        match self.step {
+            Step::Parse => None,
+            Step::Part(part) => Some(part),
+        }
+    }
+
+    /// Append the report to the file named by `AOC_REPORT_FILE`. Does nothing if the variable is not set.
+    pub fn emit(&self) -> Result<(), io::Error> {
+        append_line(&JsonValue::from(self))
+    }
+
+    /// Read all part reports from a JSON lines file. If not present, returns no reports.
+    pub fn read_all(path: &Path) -> Result<Vec<Self>, String> {
+        read_lines(path, "step", |json| PartReport::try_from(json))
+    }
+}
+
+/// A day of an in-process run that panicked. The days of such a run share one process,
+/// so its exit status can't tell which of them failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PanicReport {
+    pub day: Day,
+    pub message: String,
+}
+
+impl PanicReport {
+    /// Append the report to the file named by `AOC_REPORT_FILE`. Does nothing if the variable is not set.
+    pub fn emit(&self) -> Result<(), io::Error> {
+        append_line(&JsonValue::from(self))
+    }
+
+    /// Read all panic reports from a JSON lines file. If not present, returns no reports.
+    pub fn read_all(path: &Path) -> Result<Vec<Self>, String> {
+        read_lines(path, "panic", |json| PanicReport::try_from(json))
+    }
+}
+
+fn append_line(json: &JsonValue) -> Result<(), io::Error> {
+    let Some(path) = env::var_os(REPORT_FILE_ENV) else {
+        return Ok(());
+    };
+
+    let line = json
+        .stringify()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// Parse the lines of a JSON lines file that have `key`, i.e. the reports of one kind.
+fn read_lines<T>(
+    path: &Path,
+    key: &str,
+    parse: impl Fn(&JsonValue) -> Result<T, String>,
+) -> Result<Vec<T>, String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+
+    let mut reports = vec![];
+
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let json = JsonValue::from_str(line).or(Err("not a valid JSON line."))?;
+        let is_match = json
+            .get::<HashMap<String, JsonValue>>()
+            .is_some_and(|json| json.contains_key(key));
+
+        if is_match {
+            reports.push(parse(&json)?);
+        }
+    }
+
+    Ok(reports)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.day.year().to_string()),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        match value.step {
+            Step::Parse => {
+                map.insert("step".into(), JsonValue::String("parse".into()));
+            }
+            Step::Part(part) => {
+                map.insert("step".into(), JsonValue::String("part".into()));
+                map.insert("part".into(), JsonValue::Number(f64::from(part)));
+            }
+        }
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.stats.median.as_nanos() as f64),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        map.insert(
+            "status".into(),
+            JsonValue::String(
+                match value.status {
+                    Status::Solved => "solved",
+                    Status::Unsolved => "unsolved",
+                }
+                .into(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected report.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str_with_year(year, day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let step = match json.get("step").and_then(|v| v.get::<String>()) {
+            Some(s) if s == "parse" => Step::Parse,
+            Some(s) if s == "part" => json
+                .get("part")
+                .and_then(|v| v.get::<f64>())
+                .map(|part| *part as u8)
+                .filter(|part| (1..=2).contains(part))
+                .map(Step::Part)
+                .ok_or("Expected report.part to be 1 or 2.")?,
+            _ => return Err("Expected report.step to be a known step.".into()),
+        };
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let duration = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>())
+            .map(|nanos| Duration::from_nanos(*nanos as u64))
+            .ok_or("Expected report.duration_nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|samples| *samples as u128)
+            .ok_or("Expected report.samples to be a number.")?;
+
+        // reports without detailed statistics describe a single sample.
+        let stats = match json.get("stats") {
+            Some(stats) => BenchStats::try_from(stats)?,
+            None => BenchStats {
+                samples,
+                ..BenchStats::from_samples(&[duration])
+            },
+        };
+
+        let status = match json.get("status").and_then(|v| v.get::<String>()) {
+            Some(s) if s == "solved" => Status::Solved,
+            Some(s) if s == "unsolved" => Status::Unsolved,
+            _ => return Err("Expected report.status to be a known status.".into()),
+        };
+
+        Ok(PartReport {
+            day,
+            step,
+            answer: answer.cloned(),
+            stats,
+            status,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PanicReport> for JsonValue {
+    fn from(value: &PanicReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.day.year().to_string()),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("panic".into(), JsonValue::String(value.message.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PanicReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected report.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str_with_year(year, day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let message = json
+            .get("panic")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.panic to be a string.")?;
+
+        Ok(PanicReport {
+            day,
+            message: message.clone(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process, str::FromStr, time::Duration};
+    use tinyjson::JsonValue;
+
+    use super::{PanicReport, PartReport, Status, Step};
+    use crate::{
+        day,
+        template::{stats::BenchStats, Day, Year},
+    };
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = PartReport {
+            day: day!(6),
+            step: Step::Part(2),
+            answer: Some("Part 1: 42 (10 samples)\n@ @ @".into()),
+            stats: BenchStats::from_samples(&[
+                Duration::from_nanos(74_130),
+                Duration::from_nanos(74_200),
+            ]),
+            status: Status::Solved,
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        assert!(!line.contains('\n'));
+
+        let parsed = PartReport::try_from(&JsonValue::from_str(&line).unwrap()).unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_reports_of_other_years() {
+        let day = Day::with_year(Year::new(2025).unwrap(), 12).unwrap();
+        let report = PartReport {
+            day,
+            step: Step::Part(1),
+            answer: None,
+            stats: BenchStats::from_samples(&[Duration::from_nanos(1)]),
+            status: Status::Unsolved,
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::try_from(&JsonValue::from_str(&line).unwrap()).unwrap();
+        assert_eq!(parsed.day.year(), day.year());
+        assert_eq!(parsed.day, day);
+    }
+
+    #[test]
+    fn parses_days_above_the_calendar_of_the_current_year() {
+        let json = r#"{ "year": "2015", "day": "25", "step": "part", "part": 1, "answer": "42", "duration_nanos": 0, "samples": 1, "status": "solved" }"#;
+        let report = PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(
+            report.day,
+            Day::with_year(Year::new(2015).unwrap(), 25).unwrap()
+        );
+    }
+
+    #[test]
+    fn roundtrips_parse_reports() {
+        let report = PartReport {
+            day: day!(5),
+            step: Step::Parse,
+            answer: None,
+            stats: BenchStats::from_samples(&[Duration::from_nanos(1_000)]),
+            status: Status::Solved,
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::try_from(&JsonValue::from_str(&line).unwrap()).unwrap();
+        assert_eq!(parsed, report);
+        assert_eq!(parsed.part(), None);
+    }
+
+    #[test]
+    fn parses_unsolved_reports() {
+        let json = r#"{ "year": "2024", "day": "01", "step": "part", "part": 1, "answer": null, "duration_nanos": 0, "samples": 1, "status": "unsolved" }"#;
+        let report = PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(report.answer, None);
+        assert_eq!(report.stats.samples, 1);
+        assert_eq!(report.status, Status::Unsolved);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_reports() {
+        let json = r#"{ "year": "2024", "day": "01", "step": "part", "part": 3 }"#;
+        PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn reads_reports_by_kind() {
+        let part = PartReport {
+            day: day!(1),
+            step: Step::Part(1),
+            answer: Some("11".into()),
+            stats: BenchStats::from_samples(&[Duration::from_nanos(1_000)]),
+            status: Status::Solved,
+        };
+        let panic = PanicReport {
+            day: day!(2),
+            message: "attempt to subtract with overflow".into(),
+        };
+
+        let lines = [JsonValue::from(&part), JsonValue::from(&panic)]
+            .map(|json| json.stringify().unwrap())
+            .join("\n");
+
+        let path = env::temp_dir().join(format!("aoc-report-{}-kinds.jsonl", process::id()));
+        fs::write(&path, lines).unwrap();
+
+        let parts = PartReport::read_all(&path);
+        let panics = PanicReport::read_all(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(parts.unwrap(), vec![part]);
+        assert_eq!(panics.unwrap(), vec![panic]);
+    }
+}
@@ -1,49 +1,355 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    env, fs, io,
+    path::PathBuf,
+    process::{Command, ExitStatus},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{get_data_path, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
+    answers::{Answer, Answers},
+    report::{PartReport, Status},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use child_commands::Exit;
+
+/// Output of running a set of days.
+pub struct MultiRun {
+    pub answers: Answers,
+    pub timings: Option<Timings>,
+    pub statuses: Vec<(Day, DayStatus)>,
+}
+
+impl MultiRun {
+    /// Days that panicked, exited with an error or timed out. Days without an input did not run and are not included.
+    pub fn crashed_days(&self) -> Vec<Day> {
+        self.statuses
+            .iter()
+            .filter(|(_, status)| matches!(status, DayStatus::Failed(_) | DayStatus::TimedOut(_)))
+            .map(|(day, _)| *day)
+            .collect()
+    }
+}
+
+/// Outcome of running a single day, as shown in the summary of a run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    /// Every part that ran returned an answer.
+    Solved,
+    /// The day is not scaffolded, or a part returned `None`.
+    Unsolved,
+    /// The bin panicked or exited with a non-zero status.
+    Failed(String),
+    /// The bin was killed after running longer than the timeout.
+    TimedOut(Duration),
+    /// The bin did not run, as the input of the day has not been downloaded yet.
+    MissingInput,
+}
 
-    let mut need_space = false;
+impl DayStatus {
+    fn from_run(reports: &[PartReport], exit: Exit) -> Self {
+        let mut parts = reports.iter().filter(|r| r.part().is_some()).peekable();
+
+        match exit {
+            Exit::Failed(message) => DayStatus::Failed(message),
+            Exit::TimedOut(timeout) => DayStatus::TimedOut(timeout),
+            Exit::MissingInput => DayStatus::MissingInput,
+            Exit::Success if parts.peek().is_none() => DayStatus::Unsolved,
+            Exit::Success if parts.all(|r| r.status == Status::Solved) => DayStatus::Solved,
+            Exit::Success => DayStatus::Unsolved,
+        }
+    }
+}
+
+/// The checkout that the child `cargo` commands of a run are invoked in.
+/// The default is the current directory, building into the current target directory.
+#[derive(Clone, Debug, Default)]
+pub struct Workspace {
+    pub root: Option<PathBuf>,
+    pub target_dir: Option<PathBuf>,
+}
+
+impl Workspace {
+    /// A `cargo` command that runs in this workspace, see [`cargo_command`].
+    #[must_use]
+    pub fn cargo_command(&self) -> Command {
+        let mut cmd = cargo_command();
+
+        if let Some(root) = &self.root {
+            cmd.current_dir(root);
+        }
+
+        if let Some(target_dir) = &self.target_dir {
+            cmd.env("CARGO_TARGET_DIR", target_dir);
+        }
+
+        cmd
+    }
+
+    /// Resolve a path relative to the root of the workspace.
+    pub fn join(&self, path: &str) -> PathBuf {
+        self.root
+            .as_ref()
+            .map_or_else(|| PathBuf::from(path), |root| root.join(path))
+    }
+
+    /// Whether the input of a day has been downloaded. `cargo scaffold` creates an empty input file, which does not count.
+    pub fn has_input(&self, day: Day) -> bool {
+        let path = self.join(&get_data_path(day.year(), &format!("inputs/{day}.txt")));
+        fs::metadata(path).is_ok_and(|m| m.len() > 0)
+    }
+}
+
+/// Run a set of days. With more than one job, days run concurrently and their output is
+/// buffered, then printed in day order. Timed runs are always serial to keep benchmarks clean.
+/// If `part` is set, only that part of every day is run.
+/// If `timeout` is set, a day is killed once it runs longer. Solutions are built beforehand, so the timeout only covers running them.
+/// Fails if this build fails.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    jobs: usize,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+    workspace: &Workspace,
+) -> Result<MultiRun, Error> {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut answers: Vec<Answer> = Vec::with_capacity(days_to_run.len());
+    let mut statuses: Vec<(Day, DayStatus)> = Vec::with_capacity(days_to_run.len());
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let jobs = if is_timed { 1 } else { jobs.max(1) };
+    let timer = Instant::now();
+
+    if timeout.is_some() {
+        child_commands::build_solutions(is_release, workspace)?;
+    }
+
+    let mut collect = |day: Day, reports: Vec<PartReport>, exit: Exit| {
+        match &exit {
+            Exit::Failed(message) => println!("Failed: {message}"),
+            Exit::TimedOut(timeout) => println!("Timed out after {timeout:.1?}."),
+            Exit::MissingInput => println!("Missing input."),
+            Exit::Success if reports.is_empty() => println!("Not solved."),
+            Exit::Success => {}
+        }
+
+        if !reports.is_empty() {
+            // a day that crashed or timed out only has partial timings, which must not be stored.
+            if exit == Exit::Success {
+                timings.push(child_commands::to_timing(&reports, day));
             }
-            need_space = true;
+            answers.push(child_commands::to_answer(&reports, day));
+        }
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        statuses.push((day, DayStatus::from_run(&reports, exit)));
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    if jobs == 1 {
+        for (i, &day) in days.iter().enumerate() {
+            print_header(day, i > 0);
+            match child_commands::run_solution(day, is_timed, is_release, part, timeout, workspace)
+            {
+                Ok((reports, exit)) => collect(day, reports, exit),
+                Err(e) => collect(day, vec![], Exit::Failed(format!("{e:?}"))),
+            }
+        }
+    } else {
+        let run = |day| {
+            child_commands::run_solution_captured(day, false, is_release, part, timeout, workspace)
+        };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        run_parallel(&days, jobs, run, |i, day, output| {
+            print_header(day, i > 0);
+            match output {
+                Ok(captured) => {
+                    print!("{}", captured.stdout);
+                    eprint!("{}", captured.stderr);
+                    collect(day, captured.reports, captured.exit);
+                }
+                Err(e) => collect(day, vec![], Exit::Failed(format!("{e:?}"))),
             }
         });
+    }
+
+    print_summary(&statuses, jobs, timer.elapsed());
+
+    let answers = Answers {
+        salt: None,
+        data: answers,
+    };
 
     if is_timed {
-        let timings = Timings { data: timings };
+        let timings = Timings {
+            data: timings,
+            history: vec![],
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
+        Ok(MultiRun {
+            answers,
+            timings: Some(timings),
+            statuses,
+        })
     } else {
-        None
+        Ok(MultiRun {
+            answers,
+            timings: None,
+            statuses,
+        })
+    }
+}
+
+/// Run all days of the selected year in a single process. The days are run serially and can't time out.
+/// Reports the same statuses and summary as `run_multi`, except that timings are never collected.
+/// Fails if the `all` bin can't be built.
+pub fn run_in_process(is_release: bool, part: Option<u8>) -> Result<MultiRun, Error> {
+    let timer = Instant::now();
+    let workspace = Workspace::default();
+    let (reports, panics, status) = child_commands::run_in_process(is_release, part)?;
+    let process_failure = (!status.success()).then(|| status.to_string());
+
+    let mut answers: Vec<Answer> = vec![];
+    let mut statuses: Vec<(Day, DayStatus)> = vec![];
+
+    for day in all_days() {
+        let reports: Vec<PartReport> = reports.iter().filter(|r| r.day == day).cloned().collect();
+
+        let exit = if !workspace.join(&get_path_for_bin(day)).exists() {
+            Exit::Success
+        } else if !workspace.has_input(day) {
+            Exit::MissingInput
+        } else {
+            let panic = panics.iter().find(|p| p.day == day);
+            child_commands::to_in_process_exit(&reports, panic, process_failure.as_deref())
+        };
+
+        if !reports.is_empty() {
+            answers.push(child_commands::to_answer(&reports, day));
+        }
+
+        statuses.push((day, DayStatus::from_run(&reports, exit)));
+    }
+
+    print_summary(&statuses, 1, timer.elapsed());
+
+    Ok(MultiRun {
+        answers: Answers {
+            salt: None,
+            data: answers,
+        },
+        timings: None,
+        statuses,
+    })
+}
+
+/// Print the status of every day, followed by the number of days per status.
+fn print_summary(statuses: &[(Day, DayStatus)], jobs: usize, elapsed: Duration) {
+    println!();
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+
+    let (mut solved, mut unsolved, mut failed, mut timed_out, mut missing_input) = (0, 0, 0, 0, 0);
+
+    for (day, status) in statuses {
+        let status = match status {
+            DayStatus::Solved => {
+                solved += 1;
+                "✓ solved".to_string()
+            }
+            DayStatus::Unsolved => {
+                unsolved += 1;
+                "- unsolved".to_string()
+            }
+            DayStatus::Failed(message) => {
+                failed += 1;
+                format!("✖ failed ({message})")
+            }
+            DayStatus::TimedOut(timeout) => {
+                timed_out += 1;
+                format!("⏱ timed out after {timeout:.1?}")
+            }
+            DayStatus::MissingInput => {
+                missing_input += 1;
+                "- missing input".to_string()
+            }
+        };
+
+        println!("Day {day}: {status}");
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Total:{ANSI_RESET} {solved} solved, {failed} failed, {timed_out} timed out, {unsolved} unsolved, {missing_input} missing input. {ANSI_ITALIC}({jobs} {}, {elapsed:.2?}){ANSI_RESET}",
+        if jobs == 1 { "job" } else { "jobs" }
+    );
+}
+
+fn print_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
     }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Run days on a pool of worker threads. `on_output` is called with the index of a day and its
+/// output in day order, as soon as all days before it have finished.
+fn run_parallel<T: Send>(
+    days: &[Day],
+    jobs: usize,
+    run: impl Fn(Day) -> T + Sync,
+    mut on_output: impl FnMut(usize, Day, T),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let (next, run) = (&next, &run);
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(&day) = days.get(i) else {
+                    break;
+                };
+
+                if sender.send((i, run(day))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_index = 0;
+
+        for (i, output) in receiver {
+            pending.insert(i, output);
+
+            while let Some(output) = pending.remove(&next_index) {
+                on_output(next_index, days[next_index], output);
+                next_index += 1;
+            }
+        }
+    });
 }
 
 #[allow(dead_code)]
@@ -51,6 +357,19 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Report(String),
+    Build(ExitStatus),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "broken pipe"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Report(message) => write!(f, "{message}"),
+            Error::Build(status) => write!(f, "failed to build solutions, cargo {status}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -61,197 +380,731 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./src/bin/{}/{day}.rs", day.year())
+}
+
+/// The name of a day's bin as declared in `Cargo.toml`. E.g. like `2024-01`.
+#[must_use]
+pub fn get_bin_name(day: Day) -> String {
+    format!("{}-{day}", day.year())
+}
+
+/// Create a `cargo` command for invoking solution bins.
+/// The package variables set by the outer `cargo run` (e.g. `CARGO_MANIFEST_DIR`) must not leak into the child,
+/// as dependencies with build scripts that track them would be rebuilt on every invocation.
+#[must_use]
+pub fn cargo_command() -> Command {
+    const PACKAGE_VARS: [&str; 5] = [
+        "CARGO_PKG_",
+        "CARGO_MANIFEST_",
+        "CARGO_CRATE_NAME",
+        "CARGO_BIN_NAME",
+        "CARGO_PRIMARY_PACKAGE",
+    ];
+
+    let mut cmd = Command::new("cargo");
+
+    for (key, _) in env::vars_os() {
+        let is_package_var = key
+            .to_str()
+            .is_some_and(|key| PACKAGE_VARS.iter().any(|var| key.starts_with(var)));
+
+        if is_package_var {
+            cmd.env_remove(key);
+        }
+    }
+
+    cmd
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading the reports they emit.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{cargo_command, get_bin_name, get_path_for_bin, Error, Workspace};
+    use crate::template::{
+        report::{PanicReport, PartReport, Step, REPORT_FILE_ENV},
+        Day,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        env, fs,
+        io::{self, BufRead, BufReader, Read},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, ExitStatus, Stdio},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// How running a solution bin ended.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Exit {
+        Success,
+        /// The bin panicked or exited with a non-zero status. Holds the panic message if there is one.
+        Failed(String),
+        /// The bin was killed after running longer than the timeout.
+        TimedOut(Duration),
+        /// The bin was not run, as the input of the day is missing or empty.
+        MissingInput,
+    }
 
-        if is_release {
-            args.push("--release");
+    /// Reports, stdout and stderr of a solution bin whose output was captured instead of forwarded.
+    pub struct Captured {
+        pub reports: Vec<PartReport>,
+        pub stdout: String,
+        pub stderr: String,
+        pub exit: Exit,
+    }
+
+    /// Run the solution bin for a given day and collect the reports of its parts.
+    /// Reports of parts that finished before a panic or timeout are still collected.
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        part: Option<u8>,
+        timeout: Option<Duration>,
+        workspace: &Workspace,
+    ) -> Result<(Vec<PartReport>, Exit), Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !workspace.join(&get_path_for_bin(day)).exists() {
+            return Ok((vec![], Exit::Success));
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if !workspace.has_input(day) {
+            return Ok((vec![], Exit::MissingInput));
         }
 
+        let report_path = get_report_path(day);
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr, stderr is also kept to read panic messages from.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut child = solution_command(day, is_timed, is_release, part, &report_path, workspace)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let stdout = BufReader::new(child.stdout.take().ok_or(super::Error::BrokenPipe)?);
+        let stderr = BufReader::new(child.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let stderr_thread = thread::spawn(move || {
+            let mut captured = String::new();
+            for line in stderr.lines().map_while(Result::ok) {
+                eprintln!("{line}");
+                captured.push_str(&line);
+                captured.push('\n');
+            }
+            captured
+        });
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+        let stdout_thread = thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                println!("{line}");
+            }
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let status = wait_with_timeout(&mut child, timeout)?;
+
+        // the readers end once the pipes close, which also happens when the bin was killed.
+        stdout_thread.join().unwrap();
+        let stderr = stderr_thread.join().unwrap();
+
+        Ok((
+            read_reports(&report_path)?,
+            to_exit(status, &stderr, timeout),
+        ))
+    }
+
+    /// Run the solution bin for a given day, buffering its output so it can be printed later.
+    pub fn run_solution_captured(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        part: Option<u8>,
+        timeout: Option<Duration>,
+        workspace: &Workspace,
+    ) -> Result<Captured, Error> {
+        if !workspace.join(&get_path_for_bin(day)).exists() {
+            return Ok(Captured {
+                reports: vec![],
+                stdout: String::new(),
+                stderr: String::new(),
+                exit: Exit::Success,
+            });
+        }
+
+        if !workspace.has_input(day) {
+            return Ok(Captured {
+                reports: vec![],
+                stdout: String::new(),
+                stderr: String::new(),
+                exit: Exit::MissingInput,
+            });
+        }
+
+        let report_path = get_report_path(day);
+        let mut child = solution_command(day, is_timed, is_release, part, &report_path, workspace)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let stdout_thread = read_to_string(child.stdout.take().ok_or(Error::BrokenPipe)?);
+        let stderr_thread = read_to_string(child.stderr.take().ok_or(Error::BrokenPipe)?);
+
+        let status = wait_with_timeout(&mut child, timeout)?;
+
+        // the readers end once the pipes close, which also happens when the bin was killed.
+        let stdout = stdout_thread.join().unwrap();
+        let stderr = stderr_thread.join().unwrap();
+
+        Ok(Captured {
+            reports: read_reports(&report_path)?,
+            exit: to_exit(status, &stderr, timeout),
+            stdout,
+            stderr,
+        })
+    }
+
+    /// Build all solution bins, so that a timeout does not include compile times.
+    /// Compile errors are printed to stderr.
+    pub fn build_solutions(is_release: bool, workspace: &Workspace) -> Result<(), Error> {
+        let mut cmd = workspace.cargo_command();
+        cmd.args(["build", "--quiet", "--bins"]);
+
+        if is_release {
+            cmd.arg("--release");
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let status = cmd.stdout(Stdio::null()).status()?;
 
-        Ok(output)
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::Build(status))
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
+    fn read_to_string(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+        thread::spawn(move || {
+            let mut buf = vec![];
+            let _ = pipe.read_to_end(&mut buf);
+            String::from_utf8_lossy(&buf).into_owned()
+        })
+    }
+
+    /// Wait for a child to exit. Returns `None` if it was killed after running longer than `timeout`.
+    /// `cargo run` replaces itself with the bin on unix, so killing the child kills the solution.
+    fn wait_with_timeout(
+        child: &mut Child,
+        timeout: Option<Duration>,
+    ) -> Result<Option<ExitStatus>, io::Error> {
+        let Some(timeout) = timeout else {
+            return child.wait().map(Some);
+        };
+
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Some(status));
+            }
+
+            if Instant::now() >= deadline {
+                child.kill()?;
+                child.wait()?;
+                return Ok(None);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn to_exit(status: Option<ExitStatus>, stderr: &str, timeout: Option<Duration>) -> Exit {
+        match status {
+            None => Exit::TimedOut(timeout.unwrap_or_default()),
+            Some(status) if status.success() => Exit::Success,
+            Some(status) => {
+                Exit::Failed(parse_panic_message(stderr).unwrap_or_else(|| status.to_string()))
+            }
+        }
+    }
+
+    /// The message of the first panic in the stderr of a bin, e.g. `attempt to subtract with overflow`.
+    fn parse_panic_message(stderr: &str) -> Option<String> {
+        let mut lines = stderr.lines();
+        let line = lines.find(|line| line.contains(" panicked at "))?;
+        let (_, location) = line.split_once(" panicked at ")?;
+
+        // since Rust 1.73, the message follows on the next line: `thread 'main' panicked at src/bin/01.rs:5:5:`.
+        if location.ends_with(':') {
+            return lines.next().map(|message| message.trim().to_string());
+        }
+
+        // before, it was quoted in the same line: `thread 'main' panicked at 'message', src/bin/01.rs:5:5`.
+        let message = location.strip_prefix('\'')?.rsplit_once("', ")?.0;
+        Some(message.to_string())
+    }
+
+    fn solution_command(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        part: Option<u8>,
+        report_path: &Path,
+        workspace: &Workspace,
+    ) -> Command {
+        let mut cmd = workspace.cargo_command();
+        cmd.args(["run", "--quiet", "--bin", &get_bin_name(day)]);
+
+        if is_release {
+            cmd.arg("--release");
+        }
+
+        cmd.arg("--");
+
+        if is_timed {
+            // mirror `--time` flag to child invocations.
+            cmd.arg("--time");
+        }
+
+        if let Some(part) = part {
+            cmd.args(["--part", &part.to_string()]);
+        }
+
+        cmd.env(REPORT_FILE_ENV, report_path);
+        cmd
+    }
+
+    /// The child appends one JSON line per part to this file.
+    fn get_report_path(day: Day) -> PathBuf {
+        let report_name = format!("aoc-report-{}-{day}.jsonl", process::id());
+        let report_path = env::temp_dir().join(report_name);
+        let _ = fs::remove_file(&report_path);
+        report_path
+    }
+
+    fn read_reports(report_path: &Path) -> Result<Vec<PartReport>, Error> {
+        let reports = PartReport::read_all(report_path).map_err(Error::Report);
+        let _ = fs::remove_file(report_path);
+        reports
+    }
+
+    /// Run all days in-process via the `all` bin. Unlike `run_solution`, this invokes cargo only once.
+    /// Returns the reports of all parts, the panics of the days that panicked and the exit status of the bin.
+    /// Fails if the bin can't be built.
+    pub fn run_in_process(
+        is_release: bool,
+        part: Option<u8>,
+    ) -> Result<(Vec<PartReport>, Vec<PanicReport>, ExitStatus), Error> {
+        let mut build = cargo_command();
+        build.args(["build", "--quiet", "--bin", "all"]);
+
+        if is_release {
+            build.arg("--release");
+        }
+
+        let status = build.stdout(Stdio::null()).status()?;
+
+        if !status.success() {
+            return Err(Error::Build(status));
+        }
+
+        let report_name = format!("aoc-report-{}-all.jsonl", process::id());
+        let report_path = env::temp_dir().join(report_name);
+        let _ = fs::remove_file(&report_path);
+
+        let mut cmd = cargo_command();
+        cmd.args(["run", "--quiet", "--bin", "all"]);
+
+        if is_release {
+            cmd.arg("--release");
+        }
+
+        if let Some(part) = part {
+            cmd.args(["--", "--part", &part.to_string()]);
+        }
+
+        let status = cmd.env(REPORT_FILE_ENV, &report_path).status()?;
+        let panics = PanicReport::read_all(&report_path).map_err(Error::Report);
+
+        Ok((read_reports(&report_path)?, panics?, status))
+    }
+
+    /// How a day of an in-process run ended. All days share one exit status, so a day only failed
+    /// if it panicked, or if the process failed before the day reported any part.
+    pub fn to_in_process_exit(
+        reports: &[PartReport],
+        panic: Option<&PanicReport>,
+        process_failure: Option<&str>,
+    ) -> Exit {
+        match (panic, process_failure) {
+            (Some(panic), _) => Exit::Failed(panic.message.clone()),
+            (None, Some(failure)) if reports.is_empty() => Exit::Failed(failure.to_string()),
+            _ => Exit::Success,
+        }
+    }
+
+    pub fn to_timing(reports: &[PartReport], day: Day) -> super::Timing {
+        let mut timing = super::Timing {
             day,
             part_1: None,
             part_2: None,
+            parse: None,
             total_nanos: 0_f64,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_stats: None,
         };
 
-        output
+        for report in reports
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+            .filter(|r| r.day == day && (r.step == Step::Parse || r.answer.is_some()))
+        {
+            let duration_str = format!("{:.1?}", report.stats.median);
+            match report.step {
+                Step::Parse => {
+                    timing.parse = Some(duration_str);
+                    timing.parse_stats = Some(report.stats);
                 }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                Step::Part(1) => {
+                    timing.part_1 = Some(duration_str);
+                    timing.part_1_stats = Some(report.stats);
                 }
+                Step::Part(2) => {
+                    timing.part_2 = Some(duration_str);
+                    timing.part_2_stats = Some(report.stats);
+                }
+                _ => continue,
+            }
 
-                timings.total_nanos += nanos;
-            });
-
-        timings
-    }
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = report.stats.median.as_nanos() as f64;
+            timing.total_nanos += nanos;
+        }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+        timing
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+    pub fn to_answer(reports: &[PartReport], day: Day) -> super::Answer {
+        let mut answer = super::Answer::new(day);
 
-        Some((str_timing, parsed_timing))
-    }
+        for report in reports.iter().filter(|r| r.day == day) {
+            match report.part() {
+                Some(1) => answer.part_1.clone_from(&report.answer),
+                Some(2) => answer.part_2.clone_from(&report.answer),
+                _ => {}
+            }
+        }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+        answer
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::time::Duration;
 
-        use crate::day;
+        use super::{
+            parse_panic_message, read_to_string, to_answer, to_in_process_exit, to_timing,
+            wait_with_timeout, Exit,
+        };
+        use crate::{
+            day,
+            template::{
+                report::{PanicReport, PartReport, Status, Step},
+                stats::BenchStats,
+            },
+        };
+
+        fn get_mock_reports() -> Vec<PartReport> {
+            vec![
+                PartReport {
+                    day: day!(1),
+                    step: Step::Part(1),
+                    answer: Some("Part 2: 10 (1ms @ 5 samples)".into()),
+                    stats: BenchStats::from_samples(&[Duration::from_nanos(74_130)]),
+                    status: Status::Solved,
+                },
+                PartReport {
+                    day: day!(1),
+                    step: Step::Part(2),
+                    answer: Some("#..\n.#.".into()),
+                    stats: BenchStats::from_samples(&[
+                        Duration::from_millis(1),
+                        Duration::from_millis(2),
+                        Duration::from_millis(3),
+                    ]),
+                    status: Status::Solved,
+                },
+            ]
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        fn converts_reports_to_timings() {
+            let res = to_timing(&get_mock_reports(), day!(1));
+            assert_eq!(res.total_nanos, 2_074_130_f64);
+            assert_eq!(res.part_1.unwrap(), "74.1µs");
+            assert_eq!(res.part_2.unwrap(), "2.0ms");
+            assert_eq!(res.part_2_stats.unwrap().samples, 3);
+        }
+
+        #[test]
+        fn converts_reports_to_answers() {
+            let res = to_answer(&get_mock_reports(), day!(1));
+            assert_eq!(res.part_1.unwrap(), "Part 2: 10 (1ms @ 5 samples)");
+            assert_eq!(res.part_2.unwrap(), "#..\n.#.");
+        }
+
+        #[test]
+        fn converts_parse_reports_to_timings() {
+            let mut reports = get_mock_reports();
+            reports.push(PartReport {
+                day: day!(1),
+                step: Step::Parse,
+                answer: None,
+                stats: BenchStats::from_samples(&[Duration::from_millis(1)]),
+                status: Status::Solved,
+            });
+
+            let res = to_timing(&reports, day!(1));
+            assert_eq!(res.total_nanos, 3_074_130_f64);
+            assert_eq!(res.parse.unwrap(), "1.0ms");
+
+            let answer = to_answer(&reports, day!(1));
+            assert_eq!(answer.part_1.unwrap(), "Part 2: 10 (1ms @ 5 samples)");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_panic_messages() {
+            let stderr = [
+                "Part 1: 42 (1.0µs)",
+                "thread 'main' panicked at src/bin/2024/01.rs:5:5:",
+                "attempt to subtract with overflow",
+                "note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace",
+            ]
+            .join("\n");
+            assert_eq!(
+                parse_panic_message(&stderr).as_deref(),
+                Some("attempt to subtract with overflow")
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+
+            let stderr = "thread 'main' panicked at 'no input', src/bin/01.rs:5:5";
+            assert_eq!(parse_panic_message(stderr).as_deref(), Some("no input"));
+
+            assert_eq!(parse_panic_message("error: could not compile"), None);
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn kills_children_after_timeout() {
+            let mut child = std::process::Command::new("sleep")
+                .arg("5")
+                .spawn()
+                .unwrap();
+            let status = wait_with_timeout(&mut child, Some(Duration::from_millis(50))).unwrap();
+            assert!(status.is_none());
+
+            let mut child = std::process::Command::new("true").spawn().unwrap();
+            let status = wait_with_timeout(&mut child, Some(Duration::from_secs(5))).unwrap();
+            assert!(status.is_some_and(|s| s.success()));
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn keeps_output_of_killed_children() {
+            let mut child = std::process::Command::new("sh")
+                .args(["-c", "echo started; exec sleep 5"])
+                .stdout(std::process::Stdio::piped())
+                .spawn()
+                .unwrap();
+            let stdout = read_to_string(child.stdout.take().unwrap());
+
+            let status = wait_with_timeout(&mut child, Some(Duration::from_millis(200))).unwrap();
+            assert!(status.is_none());
+            assert_eq!(stdout.join().unwrap(), "started\n");
+        }
+
+        #[test]
+        fn handles_unsolved_parts() {
+            let reports = vec![PartReport {
+                day: day!(1),
+                step: Step::Part(1),
+                answer: None,
+                stats: BenchStats::from_samples(&[Duration::from_nanos(100)]),
+                status: Status::Unsolved,
+            }];
+
+            let timing = to_timing(&reports, day!(1));
+            assert_eq!(timing.total_nanos, 0_f64);
+            assert!(timing.part_1.is_none());
+            assert!(timing.part_2.is_none());
+
+            let answer = to_answer(&reports, day!(1));
+            assert!(answer.part_1.is_none());
+            assert!(answer.part_2.is_none());
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn attributes_in_process_failures_to_days() {
+            let reports = get_mock_reports();
+            let panic = PanicReport {
+                day: day!(1),
+                message: "index out of bounds".into(),
+            };
+
+            assert_eq!(
+                to_in_process_exit(&reports, Some(&panic), Some("exit status: 101")),
+                Exit::Failed("index out of bounds".into())
+            );
+            assert_eq!(
+                to_in_process_exit(&reports, None, Some("exit status: 101")),
+                Exit::Success
+            );
+            assert_eq!(
+                to_in_process_exit(&[], None, Some("signal: 6 (SIGABRT)")),
+                Exit::Failed("signal: 6 (SIGABRT)".into())
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(to_in_process_exit(&reports, None, None), Exit::Success);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, ffi::OsStr, fs, path::Path, process, thread, time::Duration};
+
+    use super::{child_commands::Exit, run_parallel, DayStatus, MultiRun, Workspace};
+    use crate::{
+        day,
+        template::{
+            all_days,
+            answers::Answers,
+            get_data_path,
+            report::{PartReport, Status, Step},
+            stats::BenchStats,
+            Day,
+        },
+    };
+
+    fn report(step: Step, status: Status) -> PartReport {
+        PartReport {
+            day: day!(1),
+            step,
+            answer: (status == Status::Solved).then(|| "1".into()),
+            stats: BenchStats::from_samples(&[Duration::from_nanos(100)]),
+            status,
         }
     }
+
+    #[test]
+    fn classifies_day_status() {
+        let solved = [
+            report(Step::Parse, Status::Unsolved),
+            report(Step::Part(1), Status::Solved),
+        ];
+        assert_eq!(
+            DayStatus::from_run(&solved, Exit::Success),
+            DayStatus::Solved
+        );
+
+        let partial = [
+            report(Step::Part(1), Status::Solved),
+            report(Step::Part(2), Status::Unsolved),
+        ];
+        assert_eq!(
+            DayStatus::from_run(&partial, Exit::Success),
+            DayStatus::Unsolved
+        );
+        assert_eq!(DayStatus::from_run(&[], Exit::Success), DayStatus::Unsolved);
+
+        let failed = Exit::Failed("oops".into());
+        assert_eq!(
+            DayStatus::from_run(&solved, failed),
+            DayStatus::Failed("oops".into())
+        );
+
+        let timed_out = Exit::TimedOut(Duration::from_secs(1));
+        assert_eq!(
+            DayStatus::from_run(&[], timed_out),
+            DayStatus::TimedOut(Duration::from_secs(1))
+        );
+
+        assert_eq!(
+            DayStatus::from_run(&[], Exit::MissingInput),
+            DayStatus::MissingInput
+        );
+    }
+
+    #[test]
+    fn does_not_count_missing_inputs_as_crashes() {
+        let run = MultiRun {
+            answers: Answers::default(),
+            timings: None,
+            statuses: vec![
+                (day!(1), DayStatus::Solved),
+                (day!(2), DayStatus::MissingInput),
+                (day!(3), DayStatus::Failed("oops".into())),
+            ],
+        };
+
+        assert_eq!(run.crashed_days(), vec![day!(3)]);
+    }
+
+    #[test]
+    fn detects_missing_inputs() {
+        let root = env::temp_dir().join(format!("aoc-inputs-{}", process::id()));
+        let inputs = root.join(get_data_path(day!(1).year(), "inputs"));
+        fs::create_dir_all(&inputs).unwrap();
+        fs::write(inputs.join("01.txt"), "3   4\n").unwrap();
+        fs::write(inputs.join("02.txt"), "").unwrap();
+
+        let workspace = Workspace {
+            root: Some(root.clone()),
+            target_dir: None,
+        };
+
+        let has_inputs = [day!(1), day!(2), day!(3)].map(|day| workspace.has_input(day));
+        let _ = fs::remove_dir_all(root);
+        assert_eq!(has_inputs, [true, false, false]);
+    }
+
+    #[test]
+    fn runs_days_in_parallel_in_order() {
+        let days: Vec<_> = all_days().take(6).collect();
+        let mut outputs = vec![];
+
+        // earlier days finish last, so output has to be buffered to keep day order.
+        let run = |day: Day| {
+            thread::sleep(Duration::from_millis(u64::from(12 - 2 * day.into_inner())));
+            day.into_inner()
+        };
+
+        run_parallel(&days, 3, run, |i, day, output| {
+            assert_eq!(days[i], day);
+            outputs.push(output);
+        });
+
+        assert_eq!(outputs, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn runs_cargo_in_workspace() {
+        let workspace = Workspace {
+            root: Some("/tmp/worktree".into()),
+            target_dir: Some("/tmp/target/baseline".into()),
+        };
+
+        let cmd = workspace.cargo_command();
+        assert_eq!(cmd.get_current_dir(), Some(Path::new("/tmp/worktree")));
+        assert!(cmd.get_envs().any(|(key, value)| key == "CARGO_TARGET_DIR"
+            && value == Some(OsStr::new("/tmp/target/baseline"))));
+        assert_eq!(
+            workspace.join("src/bin/2024/01.rs"),
+            Path::new("/tmp/worktree/src/bin/2024/01.rs")
+        );
+
+        let current = Workspace::default();
+        assert_eq!(current.cargo_command().get_current_dir(), None);
+        assert_eq!(current.join("data"), Path::new("data"));
+    }
 }
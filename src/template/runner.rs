@@ -1,35 +1,174 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, panic, process};
 
+use crate::template::answers::Answers;
+use crate::template::commands::Failure;
+use crate::template::report::{PanicReport, PartReport, Status, Step};
+use crate::template::run_multi::Workspace;
+use crate::template::stats::BenchStats;
+use crate::template::submission::{SubmissionHistory, Verdict};
+use crate::template::Solution;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_client, read_input, Day, Year, ANSI_ITALIC, ANSI_RESET};
 
 pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+    if !is_part_selected(part) {
+        return;
+    }
+
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats) = run_timed(func, input, |result| print_result(result, &part_str, ""));
+
+    print_result(&result, &part_str, &format_duration(&stats));
+
+    let report = PartReport {
+        day,
+        step: Step::Part(part),
+        answer: result.as_ref().map(ToString::to_string),
+        stats,
+        status: if result.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        },
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if let Err(e) = report.emit() {
+        eprintln!("Failed to write report: {e}");
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        match submit_result(result, day, part) {
+            Some(Ok(verdict)) => println!("{verdict}"),
+            Some(Err(e)) => {
+                eprintln!("failed to submit result: {e}");
+                process::exit(1);
+            }
+            None => {}
+        }
+    }
+}
+
+/// The `run` function generated by the `solution!` macro, which runs all parts of a day against an input.
+pub type RunDay = fn(&str);
+
+/// Run the days of the selected year in the current process, one after another.
+/// A day that panics does not stop the run. Its panic is reported and the process exits with an error once all days ran.
+/// Days without an input are skipped, the same as in runs with one process per day.
+pub fn run_in_process(days: &[(Day, RunDay)]) {
+    let year = Year::current();
+    let days = days.iter().filter(|(day, _)| day.year() == year);
+    let mut has_panicked = false;
+
+    for (i, &(day, run)) in days.enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        if !Workspace::default().has_input(day) {
+            println!("Missing input.");
+            continue;
+        }
+
+        let input = read_input(day);
+
+        // the default panic hook still prints the message.
+        if let Err(payload) = panic::catch_unwind(|| run(&input)) {
+            has_panicked = true;
+
+            let report = PanicReport {
+                day,
+                message: panic_message(payload.as_ref()),
+            };
+
+            if let Err(e) = report.emit() {
+                eprintln!("Failed to write report: {e}");
+            }
+        }
+    }
+
+    if has_panicked {
+        process::exit(101);
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".into())
+}
+
+/// Run a trait-based solution. Parsing is timed and reported as its own step, followed by both parts.
+pub fn run_solution<S: Solution>(input: &str, day: Day) {
+    let (parsed, stats) = run_timed(S::parse, input, |_| print!("Parse:"));
+
+    print!("\r");
+    println!("Parse:{}", format_duration(&stats));
+
+    let report = PartReport {
+        day,
+        step: Step::Parse,
+        answer: None,
+        stats,
+        status: Status::Solved,
+    };
+
+    if let Err(e) = report.emit() {
+        eprintln!("Failed to write report: {e}");
+    }
+
+    run_part(S::part_one, &parsed, day, 1);
+    run_part(S::part_two, &parsed, day, 2);
+}
+
+/// Whether a part should run. `--part <n>` restricts a run to a single part, otherwise all parts run.
+/// Exits with a usage error if the value of `--part` is not a part.
+fn is_part_selected(part: u8) -> bool {
+    let args: Vec<String> = env::args().collect();
+
+    match selected_part(&args) {
+        Ok(selected) => selected.is_none_or(|selected| selected == part),
+        Err(failure) => {
+            eprintln!("Error: {failure}");
+            process::exit(failure.exit_code());
+        }
+    }
+}
+
+/// The part passed with `--part <n>`, or `None` if all parts should run.
+fn selected_part(args: &[String]) -> Result<Option<u8>, Failure> {
+    let Some(index) = args.iter().position(|x| x == "--part") else {
+        return Ok(None);
+    };
+
+    match args.get(index + 1) {
+        Some(value) => match value.parse() {
+            Ok(part @ (1 | 2)) => Ok(Some(part)),
+            _ => Err(Failure::Usage(format!(
+                "failed to parse '{value}': expecting part 1 or 2"
+            ))),
+        },
+        None => Err(Failure::Usage(
+            "the '--part' option doesn't have an associated value".into(),
+        )),
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
-    input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+fn run_timed<I: Copy, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, BenchStats) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,16 +180,17 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let stats = if std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(&[base_time])
     };
 
-    (result, run.0, run.1)
+    (result, stats)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Bench a solution part. A warm-up phase of a tenth of the sample count runs first and is not measured.
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -59,6 +199,10 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    for _ in 0..cmp::max(bench_iterations / 10, 1) {
+        black_box(func(black_box(input)));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -67,26 +211,21 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_duration(stats: &BenchStats) -> String {
+    let BenchStats {
+        median,
+        samples,
+        outliers,
+        ..
+    } = stats;
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+    match (samples, outliers) {
+        (1, _) => format!(" ({median:.1?})"),
+        (_, 0) => format!(" ({median:.1?} @ {samples} samples)"),
+        (_, _) => format!(" ({median:.1?} @ {samples} samples, {outliers} outliers)"),
     }
 }
 
@@ -127,12 +266,13 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///  3. the submission history does not rule out the answer.
 fn submit_result<T: Display>(
     result: T,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<Verdict, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,11 +295,70 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let answer = result.to_string();
+    let mut history = SubmissionHistory::read_from_file(day.year());
+
+    if let Err(refusal) = history.check(day, part, &answer) {
+        eprintln!("Refusing to submit `{answer}`: {refusal}");
+        return None;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let verdict = aoc_client::submit(day, part, &answer);
+
+    if let Ok(verdict) = &verdict {
+        history.record(day, part, &answer, verdict.clone());
+        if let Err(e) = history.store_file(day.year()) {
+            eprintln!("Failed to store submission history: {e}");
+        }
+
+        if *verdict == Verdict::Correct {
+            let mut answers = Answers::read_from_file(day.year());
+            answers.set(day, part, &answer);
+            if let Err(e) = answers.store_file(day.year()) {
+                eprintln!("Failed to store answer: {e}");
+            }
+        }
+    }
+
+    Some(verdict)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::panic;
+
+    use super::{panic_message, selected_part};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn selects_parts() {
+        assert_eq!(selected_part(&args(&["2024-01"])).unwrap(), None);
+        assert_eq!(
+            selected_part(&args(&["2024-01", "--part", "2"])).unwrap(),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parts() {
+        assert!(selected_part(&args(&["2024-01", "--part", "3"])).is_err());
+        assert!(selected_part(&args(&["2024-01", "--part", "two"])).is_err());
+        assert!(selected_part(&args(&["2024-01", "--part"])).is_err());
+    }
+
+    #[test]
+    fn reads_panic_messages() {
+        let payload = panic::catch_unwind(|| panic!("day {} failed", 3)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "day 3 failed");
+
+        let payload = panic::catch_unwind(|| panic!("not solved")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "not solved");
+
+        let payload = panic::catch_unwind(|| panic::panic_any(3)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "panicked");
+    }
 }
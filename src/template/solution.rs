@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+/// Trait-based alternative to the `solution!` macro for days whose parts share a parsed input.
+/// The input is parsed once and handed to both parts, and the runner times parsing and each part separately.
+///
+/// ```ignore
+/// advent_of_code::solution!(5, Day05);
+///
+/// pub struct Day05;
+///
+/// impl Solution for Day05 {
+///     type Input = Vec<u64>;
+///     type PartOne = u64;
+///     type PartTwo = u64;
+///
+///     fn parse(input: &str) -> Self::Input { /* ... */ }
+///     fn part_one(input: &Self::Input) -> Option<Self::PartOne> { /* ... */ }
+///     fn part_two(input: &Self::Input) -> Option<Self::PartTwo> { /* ... */ }
+/// }
+/// ```
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part_one(input: &Self::Input) -> Option<Self::PartOne>;
+
+    fn part_two(input: &Self::Input) -> Option<Self::PartTwo>;
+}
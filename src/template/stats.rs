@@ -0,0 +1,192 @@
+/// Summary statistics of benchmark samples.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Statistics of a set of benchmark samples.
+/// Outliers are samples outside of the Tukey fences (1.5 times the interquartile range).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
+
+        let Some((&min, &max)) = nanos.first().zip(nanos.last()) else {
+            return Self::from_samples(&[Duration::ZERO]);
+        };
+
+        let len = nanos.len();
+        let median = if len.is_multiple_of(2) {
+            (nanos[len / 2 - 1] + nanos[len / 2]) / 2
+        } else {
+            nanos[len / 2]
+        };
+
+        let mean = nanos.iter().sum::<u128>() / len as u128;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = nanos
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        let q1 = percentile(&nanos, 25);
+        let q3 = percentile(&nanos, 75);
+        let fence = (q3 - q1) * 3 / 2;
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x + fence < q1 || x > q3 + fence)
+            .count();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self {
+            samples: len as u128,
+            min: to_duration(min),
+            max: to_duration(max),
+            median: to_duration(median),
+            mean: to_duration(mean),
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            p95: to_duration(percentile(&nanos, 95)),
+            outliers,
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u128], p: usize) -> u128 {
+    let rank = (sorted.len() * p).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn to_duration(nanos: u128) -> Duration {
+    Duration::from_nanos(nanos as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let durations = [
+            ("min_nanos", value.min),
+            ("max_nanos", value.max),
+            ("median_nanos", value.median),
+            ("mean_nanos", value.mean),
+            ("std_dev_nanos", value.std_dev),
+            ("p95_nanos", value.p95),
+        ];
+
+        for (key, duration) in durations {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|nanos| Duration::from_nanos(nanos as u64));
+
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            min: duration("min_nanos")?,
+            max: duration("max_nanos")?,
+            median: duration("median_nanos")?,
+            mean: duration("mean_nanos")?,
+            std_dev: duration("std_dev_nanos")?,
+            p95: duration("p95_nanos")?,
+            outliers: number("outliers")? as usize,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    use super::BenchStats;
+
+    fn to_durations(nanos: &[u64]) -> Vec<Duration> {
+        nanos.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&to_durations(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn uses_middle_values_for_even_median() {
+        let stats = BenchStats::from_samples(&to_durations(&[10, 40, 20, 30]));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn flags_outliers() {
+        let mut samples = vec![100; 19];
+        samples.push(10_000);
+        let stats = BenchStats::from_samples(&to_durations(&samples));
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.max, Duration::from_nanos(10_000));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&to_durations(&[42]));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = BenchStats::from_samples(&to_durations(&[5, 1, 4, 2, 3, 100]));
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+}
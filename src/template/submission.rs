@@ -0,0 +1,591 @@
+/// Classifies the responses the Advent of Code website returns for submitted answers.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{get_data_path, Day, Year, ANSI_BOLD, ANSI_RESET};
+
+static SUBMISSIONS_FILE_PATH: &str = "submissions.json";
+
+/// Hint returned alongside a wrong answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Outcome of an answer submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    AlreadySolved,
+    TooRecent {
+        wait: Option<Duration>,
+    },
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Classifies the message of a submission response page.
+    pub fn parse(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            let hint = if message.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if message.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+
+            Verdict::Incorrect {
+                hint,
+                wait: parse_retry_wait(message),
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooRecent {
+                wait: parse_time_left(message),
+            }
+        } else {
+            Verdict::Unknown(message.to_string())
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "⭐ {ANSI_BOLD}That's the right answer!{ANSI_RESET}"),
+            Verdict::Incorrect { hint, wait } => {
+                write!(f, "✖ {ANSI_BOLD}That's not the right answer{ANSI_RESET}")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", your answer is too high.")?,
+                    Some(Hint::TooLow) => write!(f, ", your answer is too low.")?,
+                    None => write!(f, ".")?,
+                }
+                if let Some(wait) = wait {
+                    write!(f, " Wait {} before trying again.", format_wait(wait))?;
+                }
+                Ok(())
+            }
+            Verdict::AlreadySolved => write!(f, "This part has already been solved."),
+            Verdict::TooRecent { wait } => {
+                write!(f, "⏳ You gave an answer too recently.")?;
+                if let Some(wait) = wait {
+                    write!(f, " Wait {} before trying again.", format_wait(wait))?;
+                }
+                Ok(())
+            }
+            Verdict::Unknown(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+fn format_wait(wait: &Duration) -> String {
+    let secs = wait.as_secs();
+    match (secs / 60, secs % 60) {
+        (0, s) => format!("{s}s"),
+        (m, 0) => format!("{m}m"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+/// Parses e.g. "You have 1m 22s left to wait."
+fn parse_time_left(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    time.split_whitespace()
+        .map(|part| {
+            if let Some(m) = part.strip_suffix('m') {
+                m.parse::<u64>().ok().map(|m| m * 60)
+            } else {
+                part.strip_suffix('s')?.parse::<u64>().ok()
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Parses e.g. "Please wait one minute before trying again." or "please wait 5 minutes before trying again."
+fn parse_retry_wait(message: &str) -> Option<Duration> {
+    let lower = message.to_lowercase();
+    let (_, rest) = lower.split_once("please wait ")?;
+    let (time, _) = rest.split_once(" before trying again")?;
+    let (amount, unit) = time.split_once(' ')?;
+
+    let amount = match amount {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+
+    match unit.trim_end_matches('s') {
+        "minute" => Some(Duration::from_secs(amount * 60)),
+        "second" => Some(Duration::from_secs(amount)),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An answer that was sent to the server, together with its verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub submitted_at: u64,
+}
+
+/// Reason for not sending an answer to the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong,
+    TooHigh(String),
+    TooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part has already been solved with `{answer}`.")
+            }
+            Refusal::KnownWrong => write!(f, "this answer has already been rejected."),
+            Refusal::TooHigh(bound) => {
+                write!(
+                    f,
+                    "`{bound}` was too high, so this answer is too high as well."
+                )
+            }
+            Refusal::TooLow(bound) => {
+                write!(
+                    f,
+                    "`{bound}` was too low, so this answer is too low as well."
+                )
+            }
+        }
+    }
+}
+
+/// Log of all submitted answers.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct SubmissionHistory {
+    pub data: Vec<Submission>,
+}
+
+impl SubmissionHistory {
+    /// Dehydrate the history to the JSON file of `year`.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_data_path(year, SUBMISSIONS_FILE_PATH))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history from the JSON file of `year`. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_data_path(year, SUBMISSIONS_FILE_PATH))
+            .map_err(|x| x.to_string())
+            .and_then(|json| SubmissionHistory::from_json(&json, year))
+            .unwrap_or_default()
+    }
+
+    /// Append a submission, timestamped with the current time.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, verdict: Verdict) {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        });
+    }
+
+    /// Check an answer against the previous submissions for the same day and part.
+    /// Numeric answers are also checked against the bounds given by too high / too low hints.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        let value = answer.parse::<i128>().ok();
+
+        for submission in self.data.iter().filter(|s| s.day == day && s.part == part) {
+            let Verdict::Incorrect { hint, .. } = &submission.verdict else {
+                if submission.verdict == Verdict::Correct {
+                    return Err(Refusal::AlreadySolved(submission.answer.clone()));
+                }
+                continue;
+            };
+
+            if submission.answer == answer {
+                return Err(Refusal::KnownWrong);
+            }
+
+            let (Some(value), Ok(bound)) = (value, submission.answer.parse::<i128>()) else {
+                continue;
+            };
+
+            match hint {
+                Some(Hint::TooHigh) if value >= bound => {
+                    return Err(Refusal::TooHigh(submission.answer.clone()));
+                }
+                Some(Hint::TooLow) if value <= bound => {
+                    return Err(Refusal::TooLow(submission.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<SubmissionHistory> for JsonValue {
+    fn from(value: SubmissionHistory) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl SubmissionHistory {
+    /// Parse the submission history of `year` from a JSON document. Days are stored without their year.
+    pub fn from_json(value: &str, year: Year) -> Result<Self, String> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(SubmissionHistory {
+            data: json_data
+                .iter()
+                .map(|v| Submission::from_json(v, year))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let (verdict, hint) = match &value.verdict {
+            Verdict::Correct => ("correct", None),
+            Verdict::Incorrect { hint, .. } => ("incorrect", *hint),
+            Verdict::AlreadySolved => ("already_solved", None),
+            Verdict::TooRecent { .. } => ("too_recent", None),
+            Verdict::Unknown(_) => ("unknown", None),
+        };
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert("verdict".into(), JsonValue::String(verdict.into()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "submitted_at".into(),
+            JsonValue::Number(value.submitted_at as f64),
+        );
+
+        map.insert(
+            "hint".into(),
+            match hint {
+                Some(Hint::TooHigh) => JsonValue::String("too_high".into()),
+                Some(Hint::TooLow) => JsonValue::String("too_low".into()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl Submission {
+    /// Parse a submission of `year` from JSON.
+    pub(crate) fn from_json(value: &JsonValue, year: Year) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str_with_year(year, day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .filter(|part| *part == 1 || *part == 2)
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let hint = match json.get("hint").and_then(|v| v.get::<String>()) {
+            Some(hint) if hint == "too_high" => Some(Hint::TooHigh),
+            Some(hint) if hint == "too_low" => Some(Hint::TooLow),
+            _ => None,
+        };
+
+        let verdict = match json.get("verdict").and_then(|v| v.get::<String>()) {
+            Some(v) if v == "correct" => Verdict::Correct,
+            Some(v) if v == "incorrect" => Verdict::Incorrect { hint, wait: None },
+            Some(v) if v == "already_solved" => Verdict::AlreadySolved,
+            Some(v) if v == "too_recent" => Verdict::TooRecent { wait: None },
+            Some(v) if v == "unknown" => Verdict::Unknown(String::new()),
+            _ => return Err("Expected submission.verdict to be a known verdict.".into()),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let submitted_at = json
+            .get("submitted_at")
+            .and_then(|v| v.get::<f64>())
+            .map(|t| *t as u64)
+            .ok_or("Expected submission.submitted_at to be a number.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+            submitted_at,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Hint, Verdict};
+
+    #[test]
+    fn parses_correct_answer() {
+        let verdict = Verdict::parse(
+            "That's the right answer! You are *one gold star* closer to finding the Chief Historian. [[Continue to Part Two]](/2024/day/1#part2)",
+        );
+        assert_eq!(verdict, Verdict::Correct);
+    }
+
+    #[test]
+    fn parses_wrong_answer_with_hint() {
+        let verdict = Verdict::parse(
+            "That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data; there are also some general tips on the [about page](/2024/about), or you can ask for hints on the [subreddit](https://www.reddit.com/r/adventofcode/). Please wait one minute before trying again. [[Return to Day 1]](/2024/day/1)",
+        );
+        assert_eq!(
+            verdict,
+            Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_wrong_answer_without_hint() {
+        let verdict = Verdict::parse(
+            "That's not the right answer. If you're stuck, make sure you're using the full input data. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again. [[Return to Day 1]](/2024/day/1)",
+        );
+        assert_eq!(
+            verdict,
+            Verdict::Incorrect {
+                hint: None,
+                wait: Some(Duration::from_secs(300)),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_too_low_hint() {
+        let verdict = Verdict::parse("That's not the right answer; your answer is too low.");
+        assert_eq!(
+            verdict,
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_already_solved() {
+        let verdict = Verdict::parse(
+            "You don't seem to be solving the right level. Did you already complete it? [[Return to Day 1]](/2024/day/1)",
+        );
+        assert_eq!(verdict, Verdict::AlreadySolved);
+    }
+
+    #[test]
+    fn parses_cooldown() {
+        let verdict = Verdict::parse(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 42s left to wait. [[Return to Day 1]](/2024/day/1)",
+        );
+        assert_eq!(
+            verdict,
+            Verdict::TooRecent {
+                wait: Some(Duration::from_secs(42)),
+            }
+        );
+
+        let verdict =
+            Verdict::parse("You gave an answer too recently. You have 1m 22s left to wait.");
+        assert_eq!(
+            verdict,
+            Verdict::TooRecent {
+                wait: Some(Duration::from_secs(82)),
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_messages() {
+        let verdict = Verdict::parse("Something unexpected.");
+        assert_eq!(verdict, Verdict::Unknown("Something unexpected.".into()));
+    }
+
+    #[test]
+    fn formats_verdicts() {
+        let verdict = Verdict::Incorrect {
+            hint: Some(Hint::TooLow),
+            wait: Some(Duration::from_secs(82)),
+        };
+        assert!(verdict
+            .to_string()
+            .ends_with(", your answer is too low. Wait 1m 22s before trying again."));
+    }
+
+    mod history {
+        use crate::{
+            day,
+            template::{
+                submission::{Hint, Refusal, SubmissionHistory, Verdict},
+                Year,
+            },
+        };
+
+        fn get_mock_history() -> SubmissionHistory {
+            let mut history = SubmissionHistory::default();
+            history.record(
+                day!(1),
+                1,
+                "100",
+                Verdict::Incorrect {
+                    hint: Some(Hint::TooHigh),
+                    wait: None,
+                },
+            );
+            history.record(
+                day!(1),
+                1,
+                "10",
+                Verdict::Incorrect {
+                    hint: Some(Hint::TooLow),
+                    wait: None,
+                },
+            );
+            history.record(
+                day!(1),
+                1,
+                "50",
+                Verdict::Incorrect {
+                    hint: None,
+                    wait: None,
+                },
+            );
+            history.record(day!(2), 1, "42", Verdict::Correct);
+            history
+        }
+
+        #[test]
+        fn refuses_known_wrong_answers() {
+            let history = get_mock_history();
+            assert_eq!(history.check(day!(1), 1, "50"), Err(Refusal::KnownWrong));
+        }
+
+        #[test]
+        fn refuses_answers_outside_bounds() {
+            let history = get_mock_history();
+            assert_eq!(
+                history.check(day!(1), 1, "120"),
+                Err(Refusal::TooHigh("100".into()))
+            );
+            assert_eq!(history.check(day!(1), 1, "10"), Err(Refusal::KnownWrong));
+            assert_eq!(
+                history.check(day!(1), 1, "5"),
+                Err(Refusal::TooLow("10".into()))
+            );
+        }
+
+        #[test]
+        fn refuses_solved_parts() {
+            let history = get_mock_history();
+            assert_eq!(
+                history.check(day!(2), 1, "43"),
+                Err(Refusal::AlreadySolved("42".into()))
+            );
+        }
+
+        #[test]
+        fn accepts_new_answers() {
+            let history = get_mock_history();
+            assert_eq!(history.check(day!(1), 1, "42"), Ok(()));
+            assert_eq!(history.check(day!(1), 2, "100"), Ok(()));
+            assert_eq!(history.check(day!(1), 1, "abc"), Ok(()));
+        }
+
+        #[test]
+        fn roundtrips_json() {
+            let history = get_mock_history();
+            let json = tinyjson::JsonValue::from(history.clone())
+                .stringify()
+                .unwrap();
+            let parsed = SubmissionHistory::from_json(&json, Year::current()).unwrap();
+            assert_eq!(parsed.data, history.data);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_submissions() {
+            let json = r#"{ "data": [{ "day": "01", "part": 3, "answer": "1" }] }"#.to_string();
+            SubmissionHistory::from_json(&json, Year::current()).unwrap();
+        }
+    }
+}
@@ -1,43 +1,71 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    get_data_path,
+    history::{Delta, HistoryEntry, RunInfo},
+    stats::BenchStats,
+    Day, Year,
+};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_PATH: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
+/// `part_1` and `part_2` hold the formatted median, the stats hold the full sample statistics.
+/// `parse` is only set for trait-based solutions, which time their parse step separately.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse: Option<String>,
     pub total_nanos: f64,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    pub parse_stats: Option<BenchStats>,
+}
+
+impl Timing {
+    /// Statistics of all benchmarked parts, keyed by part. Part `0` is the parse step.
+    pub fn stats(&self) -> Vec<(u8, BenchStats)> {
+        [
+            (0, self.parse_stats),
+            (1, self.part_1_stats),
+            (2, self.part_2_stats),
+        ]
+        .into_iter()
+        .filter_map(|(part, stats)| stats.map(|stats| (part, stats)))
+        .collect()
+    }
 }
 
 /// Represents benchmark times for a set of days.
+/// `data` holds the latest timing of every day, `history` holds all recorded runs in chronological order.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
     pub data: Vec<Timing>,
+    pub history: Vec<HistoryEntry>,
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of `year`.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_data_path(year, TIMINGS_FILE_PATH))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings from the JSON file of `year`. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_data_path(year, TIMINGS_FILE_PATH))
             .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
+            .and_then(|json| Timings::from_json(&json, year))
             .unwrap_or_default()
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// The history of `other` is appended to the history of `self`.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
@@ -51,8 +79,98 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.day);
+
+        let history = self
+            .history
+            .iter()
+            .chain(new.history.iter())
+            .cloned()
+            .collect();
+
+        Timings { data, history }
+    }
+
+    /// Fill the parts that were not benchmarked with their timings in `other`, e.g. after a run of a single part.
+    pub fn fill_from(&mut self, other: &Timings) {
+        for timing in &mut self.data {
+            let Some(previous) = other.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            if timing.parse_stats.is_none() {
+                timing.parse.clone_from(&previous.parse);
+                timing.parse_stats = previous.parse_stats;
+            }
+
+            if timing.part_1_stats.is_none() {
+                timing.part_1.clone_from(&previous.part_1);
+                timing.part_1_stats = previous.part_1_stats;
+            }
+
+            if timing.part_2_stats.is_none() {
+                timing.part_2.clone_from(&previous.part_2);
+                timing.part_2_stats = previous.part_2_stats;
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let total_nanos = timing
+                .stats()
+                .iter()
+                .map(|(_, stats)| stats.median.as_nanos() as f64)
+                .sum();
+            timing.total_nanos = total_nanos;
+        }
+    }
+
+    /// Append the benchmarked parts of all timings to the history.
+    pub fn record_history(&mut self, run: &RunInfo) {
+        for timing in &self.data {
+            for (part, stats) in timing.stats() {
+                self.history.push(HistoryEntry {
+                    day: timing.day,
+                    part,
+                    run: run.clone(),
+                    stats,
+                });
+            }
+        }
+    }
+
+    /// Median duration of the most recent run of a part.
+    /// Falls back to the latest timing for files that were written before history was recorded.
+    pub fn previous_median(&self, day: Day, part: u8) -> Option<Duration> {
+        self.history
+            .iter()
+            .rev()
+            .find(|entry| entry.day == day && entry.part == part)
+            .map(|entry| entry.stats.median)
+            .or_else(|| {
+                self.data
+                    .iter()
+                    .find(|timing| timing.day == day)
+                    .and_then(|timing| timing.stats().into_iter().find(|(p, _)| *p == part))
+                    .map(|(_, stats)| stats.median)
+            })
+    }
+
+    /// Compare the parts of these timings with their most recent run in `previous`.
+    pub fn compare(&self, previous: &Timings) -> Vec<Delta> {
+        self.data
+            .iter()
+            .flat_map(|timing| {
+                timing.stats().into_iter().filter_map(|(part, stats)| {
+                    previous
+                        .previous_median(timing.day, part)
+                        .map(|median| Delta {
+                            day: timing.day,
+                            part,
+                            previous: median,
+                            current: stats.median,
+                        })
+                })
+            })
+            .collect()
     }
 
     /// Sum up total duration of timings as millis.
@@ -78,28 +196,47 @@ impl From<Timings> for JsonValue {
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
 
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
 
-impl TryFrom<String> for Timings {
-    type Error = String;
+impl Timings {
+    /// Parse the timings of `year` from a JSON document. Days are stored without their year.
+    pub fn from_json(value: &str, year: Year) -> Result<Self, String> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
 
         let json_data = json
-            .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // history is optional to support files written before it was recorded.
+        let json_history = match json.get("history") {
+            Some(history) => history
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+            None => &[],
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(|v| Timing::from_json(v, year))
+                .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(|v| HistoryEntry::from_json(v, year))
                 .collect::<Result<_, _>>()?,
         })
     }
@@ -133,14 +270,45 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
         JsonValue::Object(map)
     }
 }
 
-impl TryFrom<&JsonValue> for Timing {
-    type Error = String;
-
-    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+impl Timing {
+    /// Parse the timing of a day of `year` from JSON.
+    pub(crate) fn from_json(value: &JsonValue, year: Year) -> Result<Self, String> {
         let json = value
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
@@ -148,7 +316,7 @@ impl TryFrom<&JsonValue> for Timing {
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
+            .and_then(|day| Day::from_str_with_year(year, day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
         let part_1 = json
@@ -161,16 +329,46 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // the parse step is optional, as only trait-based solutions record it.
+        let parse = match json.get("parse") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?
+                    .clone(),
+            ),
+            _ => None,
+        };
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // stats are optional to support files written before they were recorded.
+        let part_1_stats = match json.get("part_1_stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let part_2_stats = match json.get("part_2_stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let parse_stats = match json.get("parse_stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            parse,
             total_nanos,
+            part_1_stats,
+            part_2_stats,
+            parse_stats,
         })
     }
 }
@@ -190,31 +388,47 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    parse: None,
                     total_nanos: 3e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    parse: None,
                     total_nanos: 7e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    parse: None,
                     total_nanos: 4e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 },
             ],
+            history: vec![],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{timings::Timings, Year},
+        };
 
         #[test]
         fn handles_json_timings() {
             let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
-            let timings = Timings::try_from(json).unwrap();
+            let timings = Timings::from_json(&json, Year::current()).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
@@ -223,10 +437,23 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.0ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "samples": 10, "min_nanos": 900000, "max_nanos": 1200000, "median_nanos": 1000000, "mean_nanos": 1010000, "std_dev_nanos": 5000, "p95_nanos": 1100000, "outliers": 1 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::from_json(&json, Year::current()).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.median.as_nanos(), 1_000_000);
+            assert_eq!(stats.outliers, 1);
+            assert!(timing.part_2_stats.is_none());
+            assert!(timing.parse.is_none());
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
-            let timings = Timings::try_from(json).unwrap();
+            let timings = Timings::from_json(&json, Year::current()).unwrap();
             assert_eq!(timings.data.len(), 0);
         }
 
@@ -234,14 +461,14 @@ mod tests {
         #[should_panic]
         fn panics_for_invalid_json() {
             let json = r#"{}"#.to_string();
-            Timings::try_from(json).unwrap();
+            Timings::from_json(&json, Year::current()).unwrap();
         }
 
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
-            Timings::try_from(json).unwrap();
+            Timings::from_json(&json, Year::current()).unwrap();
         }
     }
 
@@ -281,11 +508,16 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    parse: None,
                     total_nanos: 3_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -295,11 +527,16 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    parse: None,
                     total_nanos: 1_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -309,11 +546,16 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    parse: None,
                     total_nanos: 0.0,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -333,8 +575,13 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    parse: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -353,8 +600,13 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    parse: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
@@ -381,4 +633,105 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod history {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{history::RunInfo, stats::BenchStats, timings::Timings, Year},
+        };
+
+        use super::get_mock_timings;
+
+        fn get_run(timestamp: u64) -> RunInfo {
+            RunInfo {
+                timestamp,
+                commit: Some("abc1234".into()),
+                profile: "release".into(),
+            }
+        }
+
+        fn get_benched_timings(part_1_millis: u64) -> Timings {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1_stats =
+                Some(BenchStats::from_samples(&[Duration::from_millis(
+                    part_1_millis,
+                )]));
+            timings
+        }
+
+        #[test]
+        fn records_benched_parts() {
+            let mut timings = get_benched_timings(10);
+            timings.record_history(&get_run(1));
+            assert_eq!(timings.history.len(), 1);
+            assert_eq!(timings.history[0].day, day!(1));
+            assert_eq!(timings.history[0].part, 1);
+        }
+
+        #[test]
+        fn appends_history_on_merge() {
+            let mut first = get_benched_timings(10);
+            first.record_history(&get_run(1));
+
+            let mut second = get_benched_timings(12);
+            second.record_history(&get_run(2));
+
+            let merged = first.merge(&second);
+            assert_eq!(merged.history.len(), 2);
+            assert_eq!(
+                merged.previous_median(day!(1), 1),
+                Some(Duration::from_millis(12))
+            );
+        }
+
+        #[test]
+        fn compares_with_previous_run() {
+            let mut previous = get_benched_timings(10);
+            previous.record_history(&get_run(1));
+
+            let deltas = get_benched_timings(15).compare(&previous);
+            assert_eq!(deltas.len(), 1);
+            assert_eq!(deltas[0].previous, Duration::from_millis(10));
+            assert_eq!(deltas[0].current, Duration::from_millis(15));
+            assert!(deltas[0].is_regression(10.0));
+        }
+
+        #[test]
+        fn compares_with_timings_without_history() {
+            let deltas = get_benched_timings(8).compare(&get_benched_timings(10));
+            assert_eq!(deltas.len(), 1);
+            assert!(deltas[0].percent() < 0.0);
+        }
+
+        #[test]
+        fn roundtrips_history() {
+            let mut timings = get_benched_timings(10);
+            timings.record_history(&get_run(1));
+
+            let json = tinyjson::JsonValue::from(timings.clone())
+                .stringify()
+                .unwrap();
+            let parsed = Timings::from_json(&json, Year::current()).unwrap();
+            assert_eq!(parsed.history, timings.history);
+        }
+
+        #[test]
+        fn fills_parts_that_were_not_run() {
+            let stored = get_benched_timings(10);
+
+            let mut timings = get_benched_timings(10);
+            timings.data.truncate(1);
+            timings.data[0].part_1 = None;
+            timings.data[0].part_1_stats = None;
+            timings.data[0].part_2_stats =
+                Some(BenchStats::from_samples(&[Duration::from_millis(20)]));
+
+            timings.fill_from(&stored);
+            assert_eq!(timings.data[0].part_1, stored.data[0].part_1);
+            assert_eq!(timings.data[0].part_1_stats, stored.data[0].part_1_stats);
+            assert_eq!(timings.data[0].total_nanos, 3e+7);
+        }
+    }
 }
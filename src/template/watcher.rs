@@ -0,0 +1,108 @@
+/// Polls a set of files for changes, without depending on a platform-specific file watcher.
+/// Also parses the output of `cargo test`, so watch mode can show a compact view of the results.
+use std::{fs, path::PathBuf, time::SystemTime};
+
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    snapshot: Vec<Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let snapshot = modified_times(&paths);
+        Self { paths, snapshot }
+    }
+
+    /// Returns `true` if a file was changed, created or removed since the last call.
+    pub fn poll(&mut self) -> bool {
+        let snapshot = modified_times(&self.paths);
+        let changed = snapshot != self.snapshot;
+        self.snapshot = snapshot;
+        changed
+    }
+}
+
+fn modified_times(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Result of a single test, as printed by the libtest harness.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+}
+
+/// Collect the results of all tests that ran. Ignored tests are skipped.
+pub fn parse_test_output(stdout: &str) -> Vec<TestResult> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, result) = line.strip_prefix("test ")?.rsplit_once(" ... ")?;
+            let passed = match result {
+                "ok" => true,
+                "FAILED" => false,
+                _ => return None,
+            };
+
+            // strip the module path, e.g. `tests::test_part_one`.
+            let name = name.rsplit("::").next().unwrap_or(name).to_string();
+            Some(TestResult { name, passed })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{parse_test_output, TestResult, Watcher};
+
+    #[test]
+    fn parses_test_output() {
+        let stdout = [
+            "running 3 tests",
+            "test tests::test_part_one ... ok",
+            "test tests::test_part_two ... FAILED",
+            "test tests::test_slow ... ignored",
+            "",
+            "test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            parse_test_output(&stdout),
+            vec![
+                TestResult {
+                    name: "test_part_one".into(),
+                    passed: true
+                },
+                TestResult {
+                    name: "test_part_two".into(),
+                    passed: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_changes() {
+        let path = env::temp_dir().join(format!("aoc-watcher-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(!watcher.poll());
+
+        fs::write(&path, "a").unwrap();
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.poll());
+    }
+}
@@ -0,0 +1,182 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The year of the first event.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first event with a shorter calendar of 12 days.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// The year used if `AOC_YEAR` is neither set at runtime nor at compile time.
+const DEFAULT_YEAR: u16 = 2024;
+
+/// A year of advent (i.e. an integer of 2015 or later).
+///
+/// Events up to 2024 have 25 days, later events have 12 days.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.days(), 12)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year with an event,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// The number of days of the event.
+    pub const fn days(self) -> u8 {
+        if self.0 >= FIRST_SHORT_YEAR {
+            12
+        } else {
+            25
+        }
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the selected year, i.e. the value of `AOC_YEAR`.
+    /// `AOC_YEAR` is set in `.cargo/config.toml` and can be overridden with the `--year` option.
+    pub fn current() -> Self {
+        env::var("AOC_YEAR")
+            .ok()
+            .and_then(|year| year.parse().ok())
+            .unwrap_or(Self::__from_env(option_env!("AOC_YEAR")))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __from_env(value: Option<&str>) -> Self {
+        let Some(value) = value else {
+            return Self(DEFAULT_YEAR);
+        };
+
+        let bytes = value.as_bytes();
+        match parse_year(bytes, 0, bytes.len()) {
+            Some(year) => year,
+            None => Self(DEFAULT_YEAR),
+        }
+    }
+
+    /// Returns the year of a solution file from the `<year>` segment of its path (e.g. `src/bin/2024/01.rs`).
+    /// Falls back to [`Year::__from_env`] if the path has no such segment.
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __from_path(path: &str, fallback: Option<&str>) -> Self {
+        let bytes = path.as_bytes();
+        let mut start = 0;
+        let mut end = 0;
+
+        while end <= bytes.len() {
+            if end == bytes.len() || bytes[end] == b'/' || bytes[end] == b'\\' {
+                if let Some(year) = parse_year(bytes, start, end) {
+                    return year;
+                }
+                start = end + 1;
+            }
+            end += 1;
+        }
+
+        Self::__from_env(fallback)
+    }
+}
+
+/// Parses `bytes[start..end]` as a four digit year.
+const fn parse_year(bytes: &[u8], start: usize, end: usize) -> Option<Year> {
+    if end - start != 4 {
+        return None;
+    }
+
+    let mut year: u16 = 0;
+    let mut i = start;
+
+    while i < end {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Year::new(year)
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn has_days_per_year() {
+        assert_eq!(Year::new(2015).unwrap().days(), 25);
+        assert_eq!(Year::new(2024).unwrap().days(), 25);
+        assert_eq!(Year::new(2025).unwrap().days(), 12);
+        assert_eq!(Year::new(2014), None);
+    }
+
+    #[test]
+    fn reads_year_from_path() {
+        assert_eq!(
+            Year::__from_path("src/bin/2023/01.rs", None),
+            Year::new(2023).unwrap()
+        );
+        assert_eq!(
+            Year::__from_path("src\\bin\\2025\\01.rs", Some("2024")),
+            Year::new(2025).unwrap()
+        );
+        assert_eq!(
+            Year::__from_path("src/bin/01.rs", Some("2022")),
+            Year::new(2022).unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2019".parse::<Year>().unwrap(), Year::new(2019).unwrap());
+        assert!("1999".parse::<Year>().is_err());
+        assert!("20x4".parse::<Year>().is_err());
+    }
+}
@@ -0,0 +1,33 @@
+// This file is generated by `cargo scaffold` and `cargo all --in-process`.
+
+/// Runs the examples of every day against the answers in its example manifest, e.g. `data/2024/examples/01.json`.
+#[test]
+fn examples() {
+    advent_of_code::template::examples::check_examples(&[
+        ("2024-01", env!("CARGO_BIN_EXE_2024-01")),
+        ("2024-02", env!("CARGO_BIN_EXE_2024-02")),
+        ("2024-03", env!("CARGO_BIN_EXE_2024-03")),
+        ("2024-04", env!("CARGO_BIN_EXE_2024-04")),
+        ("2024-05", env!("CARGO_BIN_EXE_2024-05")),
+        ("2024-06", env!("CARGO_BIN_EXE_2024-06")),
+        ("2024-07", env!("CARGO_BIN_EXE_2024-07")),
+        ("2024-08", env!("CARGO_BIN_EXE_2024-08")),
+        ("2024-09", env!("CARGO_BIN_EXE_2024-09")),
+        ("2024-10", env!("CARGO_BIN_EXE_2024-10")),
+        ("2024-11", env!("CARGO_BIN_EXE_2024-11")),
+        ("2024-12", env!("CARGO_BIN_EXE_2024-12")),
+        ("2024-13", env!("CARGO_BIN_EXE_2024-13")),
+        ("2024-14", env!("CARGO_BIN_EXE_2024-14")),
+        ("2024-15", env!("CARGO_BIN_EXE_2024-15")),
+        ("2024-16", env!("CARGO_BIN_EXE_2024-16")),
+        ("2024-17", env!("CARGO_BIN_EXE_2024-17")),
+        ("2024-18", env!("CARGO_BIN_EXE_2024-18")),
+        ("2024-19", env!("CARGO_BIN_EXE_2024-19")),
+        ("2024-20", env!("CARGO_BIN_EXE_2024-20")),
+        ("2024-21", env!("CARGO_BIN_EXE_2024-21")),
+        ("2024-22", env!("CARGO_BIN_EXE_2024-22")),
+        ("2024-23", env!("CARGO_BIN_EXE_2024-23")),
+        ("2024-24", env!("CARGO_BIN_EXE_2024-24")),
+        ("2024-25", env!("CARGO_BIN_EXE_2024-25")),
+    ]);
+}